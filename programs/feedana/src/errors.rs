@@ -1,27 +1,148 @@
 use anchor_lang::prelude::*;
 
+// Error codes are part of the client API: clients map them to localized messages, so each
+// variant's number is pinned. Anchor reports a variant as 6000 + its value here. Never
+// renumber or reuse a value; new variants go at the end with the next number.
 #[error_code]
 pub enum FeedbackBoardError {
     #[msg("Invalid IPFS CID format")]
-    InvalidIpfsCid,
+    InvalidIpfsCid = 0,
     #[msg("Board ID too long")]
-    BoardIdTooLong,
+    BoardIdTooLong = 1,
     #[msg("Board ID cannot be empty")]
-    EmptyBoardId,
+    EmptyBoardId = 2,
     #[msg("IPFS CID cannot be empty")]
-    EmptyIpfsCid,
+    EmptyIpfsCid = 3,
     #[msg("Feedback board already exists for this creator and board ID")]
-    DuplicateFeedbackBoard,
+    DuplicateFeedbackBoard = 4,
     #[msg("Insufficient funds to create feedback board")]
-    InsufficientFunds,
-    #[msg("Invalid IPFS CID length - must be between 32 and 64 characters")]
-    InvalidIpfsCidLength,
+    InsufficientFunds = 5,
+    #[msg("Invalid IPFS CID length for its encoding")]
+    InvalidIpfsCidLength = 6,
     #[msg("Board ID contains invalid characters - only alphanumeric and hyphens allowed")]
-    InvalidBoardIdChars,
+    InvalidBoardIdChars = 7,
     #[msg("Feedback board does not exist")]
-    FeedbackBoardNotFound,
+    FeedbackBoardNotFound = 8,
     #[msg("Unauthorized access - only the creator can modify this board")]
-    UnauthorizedAccess,
+    UnauthorizedAccess = 9,
     #[msg("The board creator cannot submit a feedback in their own board")]
-    CreatorCannotSubmit
+    CreatorCannotSubmit = 10,
+    #[msg("Fee exceeds the maximum allowed platform fee")]
+    FeeTooHigh = 11,
+    #[msg("Board ID is not normalized - it must be lowercase with no leading or trailing whitespace")]
+    BoardIdNotNormalized = 12,
+    #[msg("Creator has reached the maximum number of feedback boards")]
+    TooManyBoards = 13,
+    #[msg("SPL token fees are not configured for this platform")]
+    TokenFeeNotConfigured = 14,
+    #[msg("Feedback giver does not hold enough of this board's gate token")]
+    InsufficientGateBalance = 15,
+    #[msg("Feedback giver does not hold an NFT from this board's verified collection")]
+    NotCollectionHolder = 16,
+    #[msg("Feedback submitted too soon after the previous submission")]
+    SubmissionTooSoon = 17,
+    #[msg("Feedback board has expired and no longer accepts feedback")]
+    BoardExpired = 18,
+    #[msg("Board expiry must be in the future")]
+    InvalidExpiry = 19,
+    #[msg("Platform wallet does not match the configured fee wallet")]
+    InvalidFeeWallet = 20,
+    #[msg("Batch item count must be greater than zero")]
+    InvalidItemCount = 21,
+    #[msg("Board CID changed since it was read - re-read the board and retry")]
+    CidConflict = 22,
+    #[msg("This wallet is blocked from submitting feedback to this board")]
+    WalletBlocked = 23,
+    #[msg("Board blocklist is full")]
+    BlocklistFull = 24,
+    #[msg("This board only accepts feedback from allowlisted wallets")]
+    NotAllowlisted = 25,
+    #[msg("Board allowlist is full")]
+    AllowlistFull = 26,
+    #[msg("Treasury balance is too low to withdraw this amount and stay rent-exempt")]
+    InsufficientTreasuryBalance = 27,
+    #[msg("Claim exceeds the bounty remaining in this board's vault")]
+    InsufficientBounty = 28,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow = 29,
+    #[msg("Feedback board has reached its maximum number of feedback items")]
+    BoardFull = 30,
+    #[msg("Max items cannot be set below the board's current item count")]
+    InvalidMaxItems = 31,
+    #[msg("Account is not a feedback board in a known layout")]
+    UnrecognizedBoardLayout = 32,
+    #[msg("Feedback board is on an unsupported schema version - migrate it first")]
+    UnsupportedSchemaVersion = 33,
+    #[msg("Feedback board is paused and temporarily not accepting feedback")]
+    BoardPaused = 34,
+    #[msg("Too many tags for a feedback board")]
+    TooManyTags = 35,
+    #[msg("Tag must be 1-16 characters - only alphanumeric, hyphens and underscores allowed")]
+    InvalidTag = 36,
+    #[msg("Board title cannot be empty")]
+    EmptyTitle = 37,
+    #[msg("Board title too long")]
+    TitleTooLong = 38,
+    #[msg("Feedback board is frozen and its CID can no longer change")]
+    BoardFrozen = 39,
+    #[msg("Item reference must be 1-64 characters")]
+    InvalidItemRef = 40,
+    #[msg("Relayer is not the board's allowed relayer")]
+    UnauthorizedRelayer = 41,
+    #[msg("Board cosigner must also sign this instruction")]
+    CosignerRequired = 42,
+    #[msg("Program is paused by the platform admin")]
+    ProgramPaused = 43,
+    #[msg("Board ID must be at least 3 characters")]
+    BoardIdTooShort = 44,
+    #[msg("Board ID is reserved")]
+    ReservedBoardId = 45,
+    #[msg("Banned word must be 1-32 characters - only alphanumeric, hyphens and underscores allowed")]
+    InvalidBannedWord = 46,
+    #[msg("Banned word list is full")]
+    BannedWordsFull = 47,
+    #[msg("Invalid Arweave transaction ID - must be 43 base64url characters")]
+    InvalidArweaveTxId = 48,
+    #[msg("Creator board index is full")]
+    CreatorBoardIndexFull = 49,
+    #[msg("Invalid encryption scheme - required on encrypted boards, up to 16 chars, and not allowed otherwise")]
+    InvalidEncryptionScheme = 50,
+    #[msg("Board ID length limit must be between 3 and 32 characters")]
+    InvalidBoardIdLimit = 51,
+    #[msg("Fee exemption list is full")]
+    FeeExemptListFull = 52,
+    #[msg("Refund exceeds the fees collected on this board")]
+    RefundExceedsCollectedFees = 53,
+    #[msg("Tip amount must be greater than zero")]
+    InvalidTipAmount = 54,
+    #[msg("Tip recipient is not the board owner")]
+    InvalidTipRecipient = 55,
+    #[msg("CID length range must satisfy 1 <= min <= max <= the reserved CID space")]
+    InvalidCidLengthRange = 56,
+    #[msg("Feedback content is shorter than the board's minimum size")]
+    ContentTooShort = 57,
+    #[msg("Entry range must hold 1 to MAX_ENTRY_RANGE entries below feedback_seq, one account each")]
+    InvalidEntryRange = 58,
+    #[msg("Platform config has not been initialized")]
+    ConfigNotInitialized = 59,
+    #[msg("Fee increases must go through propose_fee_change and apply_fee_change")]
+    FeeIncreaseRequiresTimelock = 60,
+    #[msg("No fee change has been proposed")]
+    NoPendingFeeChange = 61,
+    #[msg("The proposed fee change is still timelocked")]
+    FeeChangeTimelocked = 62,
+    #[msg("Co-creator list is full")]
+    CoCreatorListFull = 63,
+    #[msg("Submission nonce does not match the submitter's next nonce")]
+    StaleNonce = 64,
+    #[msg("Tip fee must be at most 10000 basis points")]
+    InvalidTipFeeBps = 65,
+    #[msg("The grace window for correcting the initial CID has passed")]
+    GraceWindowExpired = 66,
+    #[msg("Wallet can't be the default pubkey")]
+    InvalidSigner = 67,
+    #[msg("New cosigner must sign to accept the role")]
+    IncomingCosignerNotSigned = 68,
+    #[msg("Entry seq doesn't match the board's next feedback_seq")]
+    EntrySeqMismatch = 69
 }
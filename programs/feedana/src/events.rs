@@ -1,15 +1,516 @@
 use anchor_lang::prelude::*;
 
+use crate::types::{CostOperation, StorageKind};
+
 #[event]
 pub struct FeedbackBoardCreated {
+    pub address: Pubkey,
+    pub bump: u8,
     pub creator: Pubkey,
     pub board_id: String,
     pub ipfs_cid: String,
+    pub title: String,
+    pub created_at: i64,
+    pub index: u64,
+    pub tags: Vec<String>,
+    pub encrypted: bool,
+    pub encryption_scheme: Option<String>,
+    pub is_listed: bool,
+    pub status: u8,
+    pub creator_verified: bool,
+    // Lamport create fee; 0 when the fee was paid in tokens
+    pub fee_paid: u64,
+    // Mint of the token create fee; None when the fee was paid in lamports
+    pub fee_mint: Option<Pubkey>,
+    // Token create fee in fee_mint base units; 0 when the fee was paid in lamports
+    pub token_fee_paid: u64,
+    // Treasury lamports after the fee landed; None when the fee was paid in tokens
+    pub treasury_balance_after: Option<u64>,
 }
 
 #[event]
 pub struct FeedbackSubmitted {
     pub board_id: String,
+    pub old_ipfs_cid: String,
+    pub new_ipfs_cid: String,
+    pub feedback_giver: Pubkey,
+    pub feedback_seq: u64,
+    pub content_hash: Option<[u8; 32]>,
+    // True when the giver is on the platform's fee exemption list and paid no fee
+    pub fee_exempt: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RelayedFeedbackSubmitted {
+    pub board_id: String,
+    pub old_ipfs_cid: String,
     pub new_ipfs_cid: String,
     pub feedback_giver: Pubkey,
+    pub relayer: Pubkey,
+    pub feedback_seq: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeedbackAppended {
+    pub board_id: String,
+    pub entry: Pubkey,
+    pub seq: u64,
+    pub ipfs_cid: String,
+    pub author: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeedbackEntryReported {
+    pub board_id: String,
+    pub entry: Pubkey,
+    pub seq: u64,
+    pub ipfs_cid: String,
+    pub author: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeedbackTipped {
+    pub board_id: String,
+    pub old_ipfs_cid: String,
+    pub new_ipfs_cid: String,
+    pub feedback_giver: Pubkey,
+    pub creator: Pubkey,
+    pub owner: Pubkey,
+    pub tip_amount: u64,
+    pub platform_tip: u64,
+    pub owner_tip: u64,
+    pub feedback_seq: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchFeedbackSubmitted {
+    pub board_id: String,
+    pub old_ipfs_cid: String,
+    pub new_ipfs_cid: String,
+    pub feedback_giver: Pubkey,
+    pub item_count: u32,
+    pub feedback_seq: u64,
+    pub total_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformConfigInitialized {
+    pub admin: Pubkey,
+    pub fee_wallet: Pubkey,
+}
+
+#[event]
+pub struct PlatformStats {
+    pub total_boards: u64,
+    pub treasury_balance: u64,
+    pub total_fees: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BannedWordsUpdated {
+    pub admin: Pubkey,
+    pub word: String,
+    pub banned: bool,
+}
+
+#[event]
+pub struct ProgramPauseUpdated {
+    pub admin: Pubkey,
+    pub program_paused: bool,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct FeeExemptionUpdated {
+    pub admin: Pubkey,
+    pub wallet: Pubkey,
+    pub exempt: bool,
+}
+
+#[event]
+pub struct FeeChangeProposed {
+    pub admin: Pubkey,
+    pub create_board_fee: u64,
+    pub submit_fee: u64,
+    pub fee_mint: Pubkey,
+    pub token_fee_amount: u64,
+    pub tip_fee_bps: u16,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct FeeChangeApplied {
+    pub admin: Pubkey,
+    pub create_board_fee: u64,
+    pub submit_fee: u64,
+    pub fee_mint: Pubkey,
+    pub token_fee_amount: u64,
+    pub tip_fee_bps: u16,
+}
+
+#[event]
+pub struct FeesUpdated {
+    pub admin: Pubkey,
+    pub create_board_fee: u64,
+    pub submit_fee: u64,
+}
+
+#[event]
+pub struct BoardOwnershipTransferred {
+    pub board_id: String,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct CidLengthRangeUpdated {
+    pub admin: Pubkey,
+    pub cid_min_len: u8,
+    pub cid_max_len: u8,
+}
+
+#[event]
+pub struct CreatorVerificationChanged {
+    pub admin: Pubkey,
+    pub creator: Pubkey,
+    pub verified: bool,
+}
+
+#[event]
+pub struct TipFeeUpdated {
+    pub admin: Pubkey,
+    pub tip_fee_bps: u16,
+}
+
+#[event]
+pub struct MaxBoardIdLenUpdated {
+    pub admin: Pubkey,
+    pub max_board_id_len: u8,
+}
+
+#[event]
+pub struct MaxBoardsPerCreatorUpdated {
+    pub admin: Pubkey,
+    pub max_boards_per_creator: u64,
+}
+
+#[event]
+pub struct BoardMetadataUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub editor: Pubkey,
+    pub old_ipfs_cid: String,
+    pub new_ipfs_cid: String,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct InitialCidCorrected {
+    pub board_id: String,
+    pub creator: Pubkey,
+    pub old_ipfs_cid: String,
+    pub new_ipfs_cid: String,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct TokenFeeUpdated {
+    pub admin: Pubkey,
+    pub fee_mint: Pubkey,
+    pub token_fee_amount: u64,
+}
+
+#[event]
+pub struct GatingUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub gate_mint: Option<Pubkey>,
+    pub gate_min_balance: u64,
+    pub allowlist_only: bool,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct BoardExpiryUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub expires_at: Option<i64>,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct BlocklistUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub wallet: Pubkey,
+    pub blocked: bool,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct AllowlistUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub wallet: Pubkey,
+    pub allowed: bool,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawal {
+    pub admin: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BoardStateReported {
+    pub creator: Pubkey,
+    pub status: u8,
+    pub owner: Pubkey,
+    pub board_id: String,
+    pub ipfs_cid: String,
+    pub title: String,
+    pub bump: u8,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub index: u64,
+    pub gate_mint: Option<Pubkey>,
+    pub gate_min_balance: u64,
+    pub collection_mint: Option<Pubkey>,
+    pub feedback_seq: u64,
+    pub last_submission_ts: i64,
+    pub min_submission_interval: i64,
+    pub expires_at: Option<i64>,
+    pub blocked: Vec<Pubkey>,
+    pub allowlist_only: bool,
+    pub allowed: Vec<Pubkey>,
+    pub max_items: u32,
+    pub item_count: u32,
+    pub min_content_bytes: u32,
+    pub is_paused: bool,
+    pub is_frozen: bool,
+    pub is_listed: bool,
+    pub creator_verified: bool,
+    pub tags: Vec<String>,
+    pub report_count: u64,
+    pub reply_count: u64,
+    pub deleted_count: u64,
+    pub collected_fees: u64,
+    pub total_interactions: u64,
+    pub allowed_relayer: Option<Pubkey>,
+    pub cosigner: Option<Pubkey>,
+    pub co_creators: Vec<Pubkey>,
+    pub content_hash: Option<[u8; 32]>,
+    pub storage_kind: StorageKind,
+    pub encrypted: bool,
+    pub encryption_scheme: Option<String>,
+    pub schema_version: u8,
+}
+
+#[event]
+pub struct EstimatedCosts {
+    pub payer: Pubkey,
+    pub operation: CostOperation,
+    pub platform_fee: u64,
+    pub rent_required: u64,
+    pub total: u64,
+}
+
+#[event]
+pub struct BoardStats {
+    pub board_id: String,
+    pub creator: Pubkey,
+    pub feedback_seq: u64,
+    pub item_count: u32,
+    pub total_interactions: u64,
+    pub last_submission_ts: i64,
+}
+
+#[event]
+pub struct BoardResolved {
+    pub creator: Pubkey,
+    pub board_id: String,
+    pub address: Pubkey,
+    pub bump: u8,
+}
+
+#[event]
+pub struct BountyDeposited {
+    pub board_id: String,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub bounty_remaining: u64,
+}
+
+#[event]
+pub struct BountyClaimed {
+    pub board_id: String,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub bounty_remaining: u64,
+}
+
+#[event]
+pub struct MaxItemsUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub max_items: u32,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct BoardMigrated {
+    pub board_id: String,
+    pub creator: Pubkey,
+    pub schema_version: u8,
+    pub index: u64,
+}
+
+#[event]
+pub struct BoardPaused {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct BoardResumed {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct BoardListingChanged {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub is_listed: bool,
+    pub status: u8,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct TagsUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub tags: Vec<String>,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct BoardTitleUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub title: String,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct BoardFrozen {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub ipfs_cid: String,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct FeedbackReported {
+    pub board_id: String,
+    pub item_ref: String,
+    pub reporter: Pubkey,
+    pub report_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorReplied {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub editor: Pubkey,
+    pub old_ipfs_cid: String,
+    pub new_ipfs_cid: String,
+    pub reply_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeedbackItemDeleted {
+    pub board_id: String,
+    pub item_ref: String,
+    pub owner: Pubkey,
+    pub old_ipfs_cid: String,
+    pub new_ipfs_cid: String,
+    pub deleted_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContributorRefunded {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+    pub collected_fees: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllowedRelayerUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub allowed_relayer: Option<Pubkey>,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct CoCreatorsUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub co_creator: Pubkey,
+    pub added: bool,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct CosignerUpdated {
+    pub board_id: String,
+    pub owner: Pubkey,
+    pub cosigner: Option<Pubkey>,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct CidValidationResult {
+    pub cid: String,
+    pub storage_kind: StorageKind,
+    pub valid: bool,
+    // Name and number of the FeedbackBoardError the CID failed with; None when valid
+    pub reason: Option<String>,
+    pub error_code: Option<u32>,
+}
+
+#[event]
+pub struct CreatorBoardsReported {
+    pub creator: Pubkey,
+    pub boards: Vec<Pubkey>,
 }
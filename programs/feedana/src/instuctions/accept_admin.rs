@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::AdminTransferred;
+
+// Second step of an admin rotation, signed by the proposed key so a mistyped address
+// can never take over the platform
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    let previous_admin = platform_config.admin;
+
+    platform_config.admin = ctx.accounts.new_admin.key();
+    platform_config.pending_admin = None;
+
+    msg!(
+        "Platform admin transferred from {} to {}",
+        previous_admin,
+        platform_config.admin
+    );
+
+    // Emit event
+    emit!(AdminTransferred {
+        previous_admin,
+        new_admin: platform_config.admin,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.pending_admin == Some(new_admin.key()) @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub new_admin: Signer<'info>,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{BannedWords, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BannedWordsUpdated;
+use crate::utils::is_identifier_char;
+
+pub fn add_banned_word(ctx: Context<AddBannedWord>, word: String) -> Result<()> {
+    // Words are stored in lowercase, the canonical form every board_id is validated into
+    let word = word.trim().to_lowercase();
+
+    // Validation: Check the word could be a board ID at all
+    let valid = !word.is_empty()
+        && word.len() <= BannedWords::MAX_BANNED_WORD_LEN
+        && word.chars().all(is_identifier_char);
+
+    if !valid {
+        return Err(InvalidBannedWord.into());
+    }
+
+    let banned_words = &mut ctx.accounts.banned_words;
+
+    // Banning an already banned word is a no-op
+    if !banned_words.words.contains(&word) {
+        // Validation: Check the list has room for another word
+        if banned_words.words.len() >= BannedWords::MAX_BANNED_WORDS {
+            return Err(BannedWordsFull.into());
+        }
+
+        banned_words.words.push(word.clone());
+    }
+
+    msg!("Board ID {} is now reserved", word);
+
+    // Emit event
+    emit!(BannedWordsUpdated {
+        admin: ctx.accounts.admin.key(),
+        word,
+        banned: true,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddBannedWord<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"banned_words"],
+        bump
+    )]
+    pub banned_words: Account<'info, BannedWords>,
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::CoCreatorsUpdated;
+use crate::utils::validate_wallet;
+
+pub fn add_co_creator(ctx: Context<AddCoCreator>, co_creator: Pubkey) -> Result<()> {
+    // Validation: Check the co-creator is a real wallet
+    validate_wallet(&co_creator)?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // Adding an existing co-creator is a no-op
+    if !feedback_board.co_creators.contains(&co_creator) {
+        // Validation: Check the co-creator list has room for another wallet
+        if feedback_board.co_creators.len() >= FeedbackBoard::MAX_CO_CREATORS {
+            return Err(CoCreatorListFull.into());
+        }
+
+        feedback_board.co_creators.push(co_creator);
+    }
+
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Co-creator {} added to feedback board {}",
+        co_creator,
+        feedback_board.board_id
+    );
+
+    // Emit event
+    emit!(CoCreatorsUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        co_creator,
+        added: true,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddCoCreator<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
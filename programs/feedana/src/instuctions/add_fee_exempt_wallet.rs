@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeeExemptionUpdated;
+use crate::utils::validate_wallet;
+
+pub fn add_fee_exempt_wallet(ctx: Context<AddFeeExemptWallet>, wallet: Pubkey) -> Result<()> {
+    // Validation: Check the wallet is a real wallet
+    validate_wallet(&wallet)?;
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    // Exempting an already exempt wallet is a no-op
+    if !platform_config.fee_exempt.contains(&wallet) {
+        // Validation: Check the list has room for another wallet
+        if platform_config.fee_exempt.len() >= PlatformConfig::MAX_FEE_EXEMPT_WALLETS {
+            return Err(FeeExemptListFull.into());
+        }
+
+        platform_config.fee_exempt.push(wallet);
+    }
+
+    msg!("Wallet {} is now exempt from the submit fee", wallet);
+
+    // Emit event
+    emit!(FeeExemptionUpdated {
+        admin: platform_config.admin,
+        wallet,
+        exempt: true,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddFeeExemptWallet<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
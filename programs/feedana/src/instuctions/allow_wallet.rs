@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::AllowlistUpdated;
+use crate::utils::validate_wallet;
+
+pub fn allow_wallet(ctx: Context<AllowWallet>, wallet: Pubkey) -> Result<()> {
+    // Validation: Check the wallet is a real wallet
+    validate_wallet(&wallet)?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // Allowing an already allowed wallet is a no-op
+    if !feedback_board.allowed.contains(&wallet) {
+        // Validation: Check the allowlist has room for another wallet
+        if feedback_board.allowed.len() >= FeedbackBoard::MAX_ALLOWED_WALLETS {
+            return Err(AllowlistFull.into());
+        }
+
+        feedback_board.allowed.push(wallet);
+    }
+
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Wallet {} allowed on feedback board {}",
+        wallet,
+        feedback_board.board_id
+    );
+
+    // Emit event
+    emit!(AllowlistUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        wallet,
+        allowed: true,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AllowWallet<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
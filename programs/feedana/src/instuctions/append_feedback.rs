@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+use anchor_spl::token::TokenAccount;
+
+use crate::types::{FeedbackEntry, PlatformConfig, Treasury};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeedbackAppended;
+use crate::instuctions::submit_feedback::check_feedback_submission;
+use crate::utils::{load_feedback_board, store_feedback_board, validate_ipfs_cid};
+
+// Append-only alternative to submit_feedback: the submission gets its own FeedbackEntry PDA
+// keyed by the board's feedback_seq, and the board's ipfs_cid is left untouched. The board
+// isn't deserialized until the handler runs, so the client passes the seq it derived the
+// entry address from and it's checked against the board here.
+pub fn append_feedback(
+    ctx: Context<AppendFeedback>,
+    ipfs_cid: String,
+    content_bytes: Option<u32>,
+    seq: u64,
+) -> Result<()> {
+    let mut feedback_board = load_feedback_board(&ctx.accounts.feedback_board)?;
+    let author = ctx.accounts.feedback_giver.key();
+
+    // Validation: Check the entry is the board's next one
+    if seq != feedback_board.feedback_seq {
+        return Err(EntrySeqMismatch.into());
+    }
+
+    // Validation: Check IPFS CID is non-empty with a valid multibase prefix, length and alphabet
+    validate_ipfs_cid(&ipfs_cid, feedback_board.storage_kind, &ctx.accounts.platform_config)?;
+
+    let item_total = check_feedback_submission(
+        &feedback_board,
+        author,
+        ctx.accounts.gate_token_account.as_deref(),
+        ctx.accounts.nft_token_account.as_deref(),
+        ctx.accounts.nft_metadata.as_ref().map(|metadata| metadata.as_ref()),
+        content_bytes,
+        1,
+    )?;
+
+    // Platform fee for feedback submission, configured by the platform admin
+    let platform_fee = ctx.accounts.platform_config.submit_fee;
+
+    // Transfer platform fee via CPI
+    let ix = transfer(&author, &ctx.accounts.treasury.key(), platform_fee);
+
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.feedback_giver.to_account_info(),
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    ctx.accounts.platform_config.record_fee(platform_fee)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    feedback_board.record_fee(platform_fee)?;
+
+    let entry = &mut ctx.accounts.feedback_entry;
+    entry.board = ctx.accounts.feedback_board.key();
+    entry.seq = feedback_board.feedback_seq;
+    entry.ipfs_cid = ipfs_cid;
+    entry.author = author;
+    entry.timestamp = now;
+    entry.bump = ctx.bumps.feedback_entry;
+
+    feedback_board.updated_at = now;
+    feedback_board.last_submission_ts = now;
+    feedback_board.feedback_seq = feedback_board
+        .feedback_seq
+        .checked_add(1)
+        .ok_or(ArithmeticOverflow)?;
+    feedback_board.total_interactions = feedback_board
+        .total_interactions
+        .checked_add(1)
+        .ok_or(ArithmeticOverflow)?;
+    feedback_board.item_count = item_total;
+
+    store_feedback_board(&ctx.accounts.feedback_board, &feedback_board)?;
+
+    msg!(
+        "Feedback entry {} appended to board {} with IPFS CID: {}",
+        entry.seq,
+        feedback_board.board_id,
+        entry.ipfs_cid
+    );
+
+    // Emit event
+    emit!(FeedbackAppended {
+        board_id: feedback_board.board_id,
+        entry: entry.key(),
+        seq: entry.seq,
+        ipfs_cid: entry.ipfs_cid.clone(),
+        author,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(ipfs_cid: String, content_bytes: Option<u32>, seq: u64)]
+pub struct AppendFeedback<'info> {
+    /// CHECK: Loaded with load_feedback_board, which checks the owner, discriminator, PDA
+    /// address and schema version and reports a missing board as FeedbackBoardNotFound
+    #[account(mut)]
+    pub feedback_board: UncheckedAccount<'info>,
+
+    // feedback_seq only grows and seq must equal it, so each entry address is used exactly once
+    #[account(
+        init,
+        payer = feedback_giver,
+        space = FeedbackEntry::SPACE,
+        seeds = [b"entry", feedback_board.key().as_ref(), &seq.to_le_bytes()],
+        bump
+    )]
+    pub feedback_entry: Account<'info, FeedbackEntry>,
+
+    #[account(mut)]
+    pub feedback_giver: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.is_initialized @ ConfigNotInitialized,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Required only when the board is token-gated
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    // Required only when the board is NFT-collection-gated
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified as the Metaplex metadata PDA of nft_token_account's mint before use
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
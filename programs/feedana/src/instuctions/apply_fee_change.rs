@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeeChangeApplied;
+
+// Step two of a timelocked fee change: makes the proposed fees active once the delay is up
+pub fn apply_fee_change(ctx: Context<ApplyFeeChange>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    let pending = platform_config.pending_fee_change.ok_or(NoPendingFeeChange)?;
+
+    // Validation: Check the timelock has elapsed
+    if Clock::get()?.unix_timestamp < pending.effective_at {
+        return Err(FeeChangeTimelocked.into());
+    }
+
+    platform_config.create_board_fee = pending.create_board_fee;
+    platform_config.submit_fee = pending.submit_fee;
+    platform_config.fee_mint = pending.fee_mint;
+    platform_config.token_fee_amount = pending.token_fee_amount;
+    platform_config.tip_fee_bps = pending.tip_fee_bps;
+    platform_config.pending_fee_change = None;
+
+    msg!(
+        "Fee change applied. Create board: {}, submit feedback: {}, token fee: {} of mint {}, \
+         tip fee: {} bps",
+        platform_config.create_board_fee,
+        platform_config.submit_fee,
+        platform_config.token_fee_amount,
+        platform_config.fee_mint,
+        platform_config.tip_fee_bps
+    );
+
+    // Emit event
+    emit!(FeeChangeApplied {
+        admin: platform_config.admin,
+        create_board_fee: platform_config.create_board_fee,
+        submit_fee: platform_config.submit_fee,
+        fee_mint: platform_config.fee_mint,
+        token_fee_amount: platform_config.token_fee_amount,
+        tip_fee_bps: platform_config.tip_fee_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyFeeChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
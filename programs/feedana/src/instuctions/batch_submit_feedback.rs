@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+use anchor_spl::token::TokenAccount;
+
+use crate::types::{PlatformConfig, Treasury};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BatchFeedbackSubmitted;
+use crate::instuctions::submit_feedback::apply_feedback_submission;
+use crate::utils::{load_feedback_board, store_feedback_board};
+
+pub fn batch_submit_feedback(
+    ctx: Context<BatchSubmitFeedback>,
+    new_ipfs_cid: String,
+    item_count: u32,
+    content_bytes: Option<u32>,
+) -> Result<()> {
+    // Validation: Check the batch carries at least one feedback item
+    if item_count == 0 {
+        return Err(InvalidItemCount.into());
+    }
+
+    let feedback_giver = ctx.accounts.feedback_giver.key();
+    let mut feedback_board = load_feedback_board(&ctx.accounts.feedback_board)?;
+
+    let old_ipfs_cid = apply_feedback_submission(
+        &mut feedback_board,
+        feedback_giver,
+        ctx.accounts.gate_token_account.as_deref(),
+        ctx.accounts.nft_token_account.as_deref(),
+        ctx.accounts.nft_metadata.as_ref().map(|metadata| metadata.as_ref()),
+        &ctx.accounts.platform_config,
+        new_ipfs_cid,
+        content_bytes,
+        item_count,
+    )?;
+
+    // Platform fee is charged per feedback item in the merged payload
+    let platform_fee = ctx
+        .accounts
+        .platform_config
+        .submit_fee
+        .checked_mul(u64::from(item_count))
+        .ok_or(ArithmeticOverflow)?;
+
+    // Transfer platform fee via CPI
+    let ix = transfer(
+        &ctx.accounts.feedback_giver.key(),
+        &ctx.accounts.treasury.key(),
+        platform_fee,
+    );
+
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.feedback_giver.to_account_info(),
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    ctx.accounts.platform_config.record_fee(platform_fee)?;
+    feedback_board.record_fee(platform_fee)?;
+
+    store_feedback_board(&ctx.accounts.feedback_board, &feedback_board)?;
+
+    msg!(
+        "Batch of {} feedback items submitted. Previous IPFS CID: {}. Updated IPFS CID: {}",
+        item_count,
+        old_ipfs_cid,
+        feedback_board.ipfs_cid
+    );
+
+    // Emit event
+    emit!(BatchFeedbackSubmitted {
+        board_id: feedback_board.board_id,
+        old_ipfs_cid,
+        new_ipfs_cid: feedback_board.ipfs_cid,
+        feedback_giver,
+        item_count,
+        feedback_seq: feedback_board.feedback_seq,
+        total_fee: platform_fee,
+        timestamp: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BatchSubmitFeedback<'info> {
+    /// CHECK: Loaded with load_feedback_board, which checks the owner, discriminator, PDA
+    /// address and schema version and reports a missing board as FeedbackBoardNotFound
+    #[account(mut)]
+    pub feedback_board: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub feedback_giver: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.is_initialized @ ConfigNotInitialized,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Required only when the board is token-gated
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    // Required only when the board is NFT-collection-gated
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified as the Metaplex metadata PDA of nft_token_account's mint before use
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
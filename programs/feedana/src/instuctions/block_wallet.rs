@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BlocklistUpdated;
+use crate::utils::validate_wallet;
+
+pub fn block_wallet(ctx: Context<BlockWallet>, wallet: Pubkey) -> Result<()> {
+    // Validation: Check the wallet is a real wallet
+    validate_wallet(&wallet)?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // Blocking an already blocked wallet is a no-op
+    if !feedback_board.blocked.contains(&wallet) {
+        // Validation: Check the blocklist has room for another wallet
+        if feedback_board.blocked.len() >= FeedbackBoard::MAX_BLOCKED_WALLETS {
+            return Err(BlocklistFull.into());
+        }
+
+        feedback_board.blocked.push(wallet);
+    }
+
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Wallet {} blocked on feedback board {}",
+        wallet,
+        feedback_board.board_id
+    );
+
+    // Emit event
+    emit!(BlocklistUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        wallet,
+        blocked: true,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BlockWallet<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
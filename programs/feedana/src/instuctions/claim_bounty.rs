@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{BountyVault, FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BountyClaimed;
+
+pub fn claim_bounty(ctx: Context<ClaimBounty>, amount: u64) -> Result<()> {
+    let bounty_vault = &mut ctx.accounts.bounty_vault;
+
+    // Validation: Check the claim is covered by the unclaimed bounty; the vault's rent
+    // reserve is never part of bounty_remaining
+    bounty_vault.bounty_remaining = bounty_vault
+        .bounty_remaining
+        .checked_sub(amount)
+        .ok_or(InsufficientBounty)?;
+
+    // The vault is program-owned, so lamports are moved directly rather than via CPI
+    bounty_vault.sub_lamports(amount)?;
+    ctx.accounts.recipient.add_lamports(amount)?;
+
+    msg!(
+        "Paid {} lamports of bounty from feedback board {} to {}",
+        amount,
+        ctx.accounts.feedback_board.board_id,
+        ctx.accounts.recipient.key()
+    );
+
+    // Emit event
+    emit!(BountyClaimed {
+        board_id: ctx.accounts.feedback_board.board_id.clone(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        bounty_remaining: bounty_vault.bounty_remaining,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimBounty<'info> {
+    #[account(
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    #[account(
+        mut,
+        seeds = [b"bounty_vault", feedback_board.key().as_ref()],
+        bump
+    )]
+    pub bounty_vault: Account<'info, BountyVault>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Any account can receive lamports; the board owner chooses the contributor
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
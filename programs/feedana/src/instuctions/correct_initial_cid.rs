@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::InitialCidCorrected;
+use crate::utils::validate_ipfs_cid;
+
+// Fixes a wrong CID pasted at creation without recreating the board. No fee is charged
+// and feedback_seq is untouched, since no feedback is involved.
+pub fn correct_initial_cid(ctx: Context<CorrectInitialCid>, new_ipfs_cid: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let feedback_board = &ctx.accounts.feedback_board;
+
+    // Validation: Check the board is still inside the grace window. The window also closes
+    // once feedback arrives, because the CID then no longer holds the initial payload.
+    let window_end = feedback_board
+        .created_at
+        .checked_add(FeedbackBoard::CID_CORRECTION_WINDOW)
+        .ok_or(ArithmeticOverflow)?;
+    if now > window_end || feedback_board.feedback_seq > 0 {
+        return Err(GraceWindowExpired.into());
+    }
+
+    // Validation: Check the board wasn't migrated. Migration resets created_at and
+    // feedback_seq, so a board with history would otherwise look brand new here.
+    if feedback_board.migrated {
+        return Err(GraceWindowExpired.into());
+    }
+
+    // Validation: Check IPFS CID is non-empty with a valid multibase prefix, length and alphabet
+    validate_ipfs_cid(
+        &new_ipfs_cid,
+        feedback_board.storage_kind,
+        &ctx.accounts.platform_config,
+    )?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    let old_ipfs_cid = std::mem::replace(&mut feedback_board.ipfs_cid, new_ipfs_cid);
+    feedback_board.content_hash = None;
+    feedback_board.updated_at = now;
+
+    msg!(
+        "Initial IPFS CID corrected. Previous IPFS CID: {}. Updated IPFS CID: {}",
+        old_ipfs_cid,
+        feedback_board.ipfs_cid
+    );
+
+    // Emit event
+    emit!(InitialCidCorrected {
+        board_id: feedback_board.board_id.clone(),
+        creator: feedback_board.creator,
+        old_ipfs_cid,
+        new_ipfs_cid: feedback_board.ipfs_cid.clone(),
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CorrectInitialCid<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = creator @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion,
+        constraint = !feedback_board.is_frozen @ BoardFrozen
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
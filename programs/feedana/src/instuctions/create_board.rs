@@ -2,73 +2,274 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::system_instruction::transfer;
 
-use crate::types::FeedbackBoard;
+use crate::types::{
+    BannedWords, BoardCounter, CreateBoardOptions, CreatorBoardIndex, CreatorStats, FeedbackBoard,
+    PlatformConfig, Treasury,
+};
 use crate::errors::FeedbackBoardError::*;
 use crate::events::FeedbackBoardCreated;
-
-const PLATFORM_FEE_WALLET: &str = "96fN4Eegj84PaUcyEJrxUztDjo7Q7MySJzV2skLfgchY";
+use crate::utils::{
+    validate_board_id, validate_board_title, validate_encryption, validate_ipfs_cid,
+    validate_tags,
+};
 
 pub fn create_feedback_board(
     ctx: Context<CreateFeedbackBoard>,
     board_id: String,
     ipfs_cid: String,
+    title: String,
+    options: CreateBoardOptions,
 ) -> Result<()> {
-    // Validation: Check if board_id is empty
-    if board_id.trim().is_empty() {
-        return Err(EmptyBoardId.into());
-    }
+    let creator = ctx.accounts.creator.key();
 
-    // Validation: Check if board_id is too long (max 32 chars for efficiency)
-    if board_id.len() > 32 {
-        return Err(BoardIdTooLong.into());
-    }
+    initialize_feedback_board(
+        &mut ctx.accounts.feedback_board,
+        &mut ctx.accounts.creator_stats,
+        &mut ctx.accounts.board_counter,
+        &ctx.accounts.platform_config,
+        &ctx.accounts.banned_words,
+        creator,
+        ctx.bumps.feedback_board,
+        board_id,
+        ipfs_cid,
+        title,
+        options,
+    )?;
 
-    // Validation: Check if board_id contains only valid characters (alphanumeric and hyphens)
-    if !board_id.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
-        return Err(InvalidBoardIdChars.into());
-    }
+    // Platform fee for board creation, configured by the platform admin
+    let platform_fee = ctx.accounts.platform_config.create_board_fee;
 
-    // Validation: Check if ipfs_cid is empty
-    if ipfs_cid.trim().is_empty() {
-        return Err(EmptyIpfsCid.into());
-    }
+    // Validation: Check the creator can pay the fee, the creator index's rent top-up and
+    // stay rent-exempt. The board's own rent was already taken by `init_if_needed`, so only
+    // these remain to be covered, and they're checked before any of them is charged
+    let creator_info = ctx.accounts.creator.to_account_info();
+    let index_top_up = creator_board_index_top_up(&ctx.accounts.creator_board_index)?;
+    let required_balance = Rent::get()?
+        .minimum_balance(creator_info.data_len())
+        .checked_add(platform_fee)
+        .and_then(|balance| balance.checked_add(index_top_up))
+        .ok_or(ArithmeticOverflow)?;
 
-    // Validation: Check IPFS CID length (typical IPFS CIDs are 32-64 characters)
-    if ipfs_cid.len() < 32 || ipfs_cid.len() > 64 {
-        return Err(InvalidIpfsCidLength.into());
+    if creator_info.lamports() < required_balance {
+        msg!(
+            "Creator balance {} is below the {} lamports needed for the fee and rent exemption",
+            creator_info.lamports(),
+            required_balance
+        );
+        return Err(InsufficientFunds.into());
     }
 
-    // Validation: Basic IPFS CID format check (should start with Qm for base58 or b for base32)
-    if !ipfs_cid.starts_with("Qm") && !ipfs_cid.starts_with("b") {
-        return Err(InvalidIpfsCid.into());
-    }
-
-    let feedback_board = &mut ctx.accounts.feedback_board;
-
-    // Initialize feedback board
-    feedback_board.creator = ctx.accounts.creator.key();
-    feedback_board.ipfs_cid = ipfs_cid;
-    feedback_board.board_id = board_id;
-
-    // Fixed platform fee for board creation: 10 lamports
-    const PLATFORM_FEE_CREATE_BOARD: u64 = 10;
+    let feedback_board = ctx.accounts.feedback_board.key();
+    append_to_creator_board_index(
+        &mut ctx.accounts.creator_board_index,
+        &ctx.accounts.creator,
+        &ctx.accounts.system_program,
+        feedback_board,
+    )?;
 
     // Transfer platform fee via CPI
     let ix = transfer(
         &ctx.accounts.creator.key(),
-        &ctx.accounts.platform_wallet.key(),
-        PLATFORM_FEE_CREATE_BOARD,
+        &ctx.accounts.treasury.key(),
+        platform_fee,
     );
 
     invoke(
         &ix,
         &[
             ctx.accounts.creator.to_account_info(),
-            ctx.accounts.platform_wallet.to_account_info(),
+            ctx.accounts.treasury.to_account_info(),
             ctx.accounts.system_program.to_account_info(),
         ],
     )?;
 
+    ctx.accounts.platform_config.record_fee(platform_fee)?;
+
+    let treasury_balance_after = ctx.accounts.treasury.to_account_info().lamports();
+
+    emit_feedback_board_created(
+        &ctx.accounts.feedback_board,
+        platform_fee,
+        None,
+        Some(treasury_balance_after),
+    );
+
+    Ok(())
+}
+
+// Validates the creation arguments and initializes the board along with its creator
+// stats and global index. Shared by every board creation path; the caller charges the fee.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn initialize_feedback_board(
+    feedback_board: &mut FeedbackBoard,
+    creator_stats: &mut CreatorStats,
+    board_counter: &mut BoardCounter,
+    platform_config: &PlatformConfig,
+    banned_words: &BannedWords,
+    creator: Pubkey,
+    bump: u8,
+    board_id: String,
+    ipfs_cid: String,
+    title: String,
+    options: CreateBoardOptions,
+) -> Result<()> {
+    // Validation: Check the board PDA isn't already initialized. The account uses
+    // init_if_needed so an existing board reaches this check instead of failing
+    // generically inside the system program's create_account
+    if feedback_board.creator != Pubkey::default() {
+        return Err(DuplicateFeedbackBoard.into());
+    }
+
+    // Validation: Check board_id is a normalized identifier within the length limits
+    validate_board_id(&board_id, platform_config)?;
+
+    // Validation: Check board_id isn't reserved. Both sides are lowercase, so the match
+    // is case-insensitive
+    if banned_words.words.contains(&board_id) {
+        return Err(ReservedBoardId.into());
+    }
+
+    // Validation: Check IPFS CID is non-empty with a valid multibase prefix, length and alphabet
+    validate_ipfs_cid(&ipfs_cid, options.storage_kind, platform_config)?;
+
+    // Validation: Check the title is non-empty and fits in the account
+    validate_board_title(&title)?;
+
+    // Validation: Check discovery tags are within the caps and character set
+    validate_tags(&options.tags)?;
+
+    // Validation: Check an encrypted board names its scheme, and only an encrypted one does
+    validate_encryption(options.encrypted, options.encryption_scheme.as_deref())?;
+
+    // Validation: Check the creator is below the per-creator board cap
+    if creator_stats.board_count >= platform_config.max_boards_per_creator {
+        return Err(TooManyBoards.into());
+    }
+
+    // First board for this creator initializes their stats account
+    creator_stats.creator = creator;
+    creator_stats.board_count = creator_stats
+        .board_count
+        .checked_add(1)
+        .ok_or(ArithmeticOverflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validation: Check an optional expiry lies in the future
+    if options.expires_at.is_some_and(|expires_at| expires_at <= now) {
+        return Err(InvalidExpiry.into());
+    }
+
+    // Initialize feedback board
+    feedback_board.creator = creator;
+    feedback_board.ipfs_cid = ipfs_cid;
+    feedback_board.board_id = board_id;
+    feedback_board.title = title;
+    feedback_board.bump = bump;
+    feedback_board.owner = creator;
+    feedback_board.created_at = now;
+    feedback_board.updated_at = now;
+    feedback_board.gate_mint = options.gate_mint;
+    feedback_board.gate_min_balance = options.gate_min_balance;
+    feedback_board.collection_mint = options.collection_mint;
+    feedback_board.feedback_seq = 0;
+    feedback_board.last_submission_ts = 0;
+    feedback_board.min_submission_interval = options.min_submission_interval;
+    feedback_board.expires_at = options.expires_at;
+    feedback_board.blocked = Vec::new();
+    feedback_board.allowlist_only = options.allowlist_only;
+    feedback_board.allowed = Vec::new();
+    feedback_board.max_items = options.max_items;
+    feedback_board.min_content_bytes = options.min_content_bytes;
+    feedback_board.item_count = 0;
+    feedback_board.is_paused = false;
+    feedback_board.is_frozen = false;
+    feedback_board.is_listed = true;
+    feedback_board.creator_verified = creator_stats.verified;
+    feedback_board.migrated = false;
+    feedback_board.status = FeedbackBoard::STATUS_ACTIVE;
+    feedback_board.tags = options.tags;
+    feedback_board.report_count = 0;
+    feedback_board.reply_count = 0;
+    feedback_board.deleted_count = 0;
+    feedback_board.collected_fees = 0;
+    feedback_board.total_interactions = 0;
+    feedback_board.allowed_relayer = None;
+    feedback_board.cosigner = None;
+    feedback_board.co_creators = Vec::new();
+    feedback_board.content_hash = None;
+    feedback_board.storage_kind = options.storage_kind;
+    feedback_board.encrypted = options.encrypted;
+    feedback_board.encryption_scheme = options.encryption_scheme;
+    feedback_board.schema_version = FeedbackBoard::CURRENT_SCHEMA_VERSION;
+
+    // Assign the next dense index in creation order
+    feedback_board.index = board_counter.total;
+    board_counter.total = board_counter
+        .total
+        .checked_add(1)
+        .ok_or(ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+// Appends a new board to its creator's index, growing the account by one entry and
+// topping up its rent from the creator. Shared by every board creation path.
+pub(crate) fn append_to_creator_board_index<'info>(
+    creator_board_index: &mut Account<'info, CreatorBoardIndex>,
+    creator: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    feedback_board: Pubkey,
+) -> Result<()> {
+    // Validation: Check the index has room for another board
+    if creator_board_index.boards.len() >= CreatorBoardIndex::MAX_INDEXED_BOARDS {
+        return Err(CreatorBoardIndexFull.into());
+    }
+
+    let index_info = creator_board_index.to_account_info();
+    let new_space = CreatorBoardIndex::space_for(creator_board_index.boards.len() + 1);
+
+    // Top up rent for the larger account before growing it
+    let top_up = creator_board_index_top_up(creator_board_index)?;
+    if top_up > 0 {
+        let ix = transfer(&creator.key(), &index_info.key(), top_up);
+
+        invoke(
+            &ix,
+            &[
+                creator.to_account_info(),
+                index_info.clone(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    index_info.resize(new_space)?;
+
+    creator_board_index.creator = creator.key();
+    creator_board_index.boards.push(feedback_board);
+
+    Ok(())
+}
+
+// Lamports the creator pays to keep their index rent-exempt once it grows by one entry
+pub(crate) fn creator_board_index_top_up(
+    creator_board_index: &Account<CreatorBoardIndex>,
+) -> Result<u64> {
+    let new_space = CreatorBoardIndex::space_for(creator_board_index.boards.len() + 1);
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(new_space);
+
+    Ok(rent_exempt_minimum.saturating_sub(creator_board_index.to_account_info().lamports()))
+}
+
+// `token_fee` is the fee mint and amount when the create fee was paid in tokens, in which
+// case `fee_paid` is 0, so lamport and token amounts never share a field
+pub(crate) fn emit_feedback_board_created(
+    feedback_board: &Account<FeedbackBoard>,
+    fee_paid: u64,
+    token_fee: Option<(Pubkey, u64)>,
+    treasury_balance_after: Option<u64>,
+) {
     msg!(
         "Feedback board created with IPFS CID: {}",
         feedback_board.ipfs_cid
@@ -76,35 +277,89 @@ pub fn create_feedback_board(
 
     // Emit event
     emit!(FeedbackBoardCreated {
+        address: feedback_board.key(),
+        bump: feedback_board.bump,
         creator: feedback_board.creator,
         board_id: feedback_board.board_id.clone(),
         ipfs_cid: feedback_board.ipfs_cid.clone(),
+        title: feedback_board.title.clone(),
+        created_at: feedback_board.created_at,
+        index: feedback_board.index,
+        tags: feedback_board.tags.clone(),
+        encrypted: feedback_board.encrypted,
+        encryption_scheme: feedback_board.encryption_scheme.clone(),
+        is_listed: feedback_board.is_listed,
+        creator_verified: feedback_board.creator_verified,
+        status: feedback_board.status,
+        fee_paid,
+        fee_mint: token_fee.map(|(fee_mint, _)| fee_mint),
+        token_fee_paid: token_fee.map_or(0, |(_, amount)| amount),
+        treasury_balance_after,
     });
-
-    Ok(())
 }
 
 #[derive(Accounts)]
 #[instruction(board_id: String)]
 pub struct CreateFeedbackBoard<'info> {
     #[account(
-        init,
+        init_if_needed,
         payer = creator,
-        space = 8 + 32 + 64 + 32, // discriminator + creator pubkey + ipfs_cid + board_id
+        space = FeedbackBoard::SPACE,
+        // board_id is validated to be normalized, so the seed is always the canonical form
         seeds = [b"feedback_board", creator.key().as_ref(), board_id.as_bytes()],
         bump
     )]
     pub feedback_board: Account<'info, FeedbackBoard>,
 
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorStats::SPACE,
+        seeds = [b"creator_stats", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorBoardIndex::space_for(0),
+        seeds = [b"creator_board_index", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_board_index: Account<'info, CreatorBoardIndex>,
+
+    #[account(
+        mut,
+        seeds = [b"board_counter"],
+        bump
+    )]
+    pub board_counter: Account<'info, BoardCounter>,
+
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    /// CHECK: This is safe as we're only transferring to this hardcoded address
     #[account(
         mut,
-        address = PLATFORM_FEE_WALLET.parse::<Pubkey>().unwrap()
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.is_initialized @ ConfigNotInitialized,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [b"banned_words"],
+        bump
+    )]
+    pub banned_words: Account<'info, BannedWords>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
     )]
-    pub platform_wallet: UncheckedAccount<'info>,
+    pub treasury: Account<'info, Treasury>,
 
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::types::CreateBoardOptions;
+use crate::errors::FeedbackBoardError::*;
+use crate::instuctions::create_board::{create_feedback_board, CreateFeedbackBoard};
+
+// Retry-safe variant of create_feedback_board for clients that may send the same create
+// twice. Takes the same accounts; a repeat of an earlier create succeeds without charging.
+pub fn create_feedback_board_idempotent(
+    ctx: Context<CreateFeedbackBoard>,
+    board_id: String,
+    ipfs_cid: String,
+    title: String,
+    options: CreateBoardOptions,
+) -> Result<()> {
+    let feedback_board = &ctx.accounts.feedback_board;
+
+    // An existing board already matches creator and board_id through its PDA seeds, so
+    // only the CID tells a retried create apart from a clash with a different board
+    if feedback_board.creator != Pubkey::default() {
+        if feedback_board.ipfs_cid != ipfs_cid {
+            return Err(DuplicateFeedbackBoard.into());
+        }
+
+        msg!(
+            "Feedback board {} already exists with IPFS CID: {}",
+            feedback_board.board_id,
+            feedback_board.ipfs_cid
+        );
+
+        return Ok(());
+    }
+
+    create_feedback_board(ctx, board_id, ipfs_cid, title, options)
+}
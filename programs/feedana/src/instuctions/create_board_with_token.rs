@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::types::{
+    BannedWords, BoardCounter, CreateBoardOptions, CreatorBoardIndex, CreatorStats, FeedbackBoard,
+    PlatformConfig,
+};
+use crate::errors::FeedbackBoardError::*;
+use crate::instuctions::create_board::{
+    append_to_creator_board_index, emit_feedback_board_created, initialize_feedback_board,
+};
+
+pub fn create_feedback_board_with_token(
+    ctx: Context<CreateFeedbackBoardWithToken>,
+    board_id: String,
+    ipfs_cid: String,
+    title: String,
+    options: CreateBoardOptions,
+) -> Result<()> {
+    let creator = ctx.accounts.creator.key();
+
+    initialize_feedback_board(
+        &mut ctx.accounts.feedback_board,
+        &mut ctx.accounts.creator_stats,
+        &mut ctx.accounts.board_counter,
+        &ctx.accounts.platform_config,
+        &ctx.accounts.banned_words,
+        creator,
+        ctx.bumps.feedback_board,
+        board_id,
+        ipfs_cid,
+        title,
+        options,
+    )?;
+
+    let feedback_board = ctx.accounts.feedback_board.key();
+    append_to_creator_board_index(
+        &mut ctx.accounts.creator_board_index,
+        &ctx.accounts.creator,
+        &ctx.accounts.system_program,
+        feedback_board,
+    )?;
+
+    // Platform fee in the configured SPL token
+    let token_fee = ctx.accounts.platform_config.token_fee_amount;
+
+    // Transfer platform fee via token program CPI
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator_token_account.to_account_info(),
+                to: ctx.accounts.platform_token_account.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        token_fee,
+    )?;
+
+    emit_feedback_board_created(
+        &ctx.accounts.feedback_board,
+        0,
+        Some((ctx.accounts.platform_config.fee_mint, token_fee)),
+        None,
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(board_id: String)]
+pub struct CreateFeedbackBoardWithToken<'info> {
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = FeedbackBoard::SPACE,
+        // board_id is validated to be normalized, so the seed is always the canonical form
+        seeds = [b"feedback_board", creator.key().as_ref(), board_id.as_bytes()],
+        bump
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorStats::SPACE,
+        seeds = [b"creator_stats", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorBoardIndex::space_for(0),
+        seeds = [b"creator_board_index", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_board_index: Account<'info, CreatorBoardIndex>,
+
+    #[account(
+        mut,
+        seeds = [b"board_counter"],
+        bump
+    )]
+    pub board_counter: Account<'info, BoardCounter>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.is_initialized @ ConfigNotInitialized,
+        constraint = !platform_config.program_paused @ ProgramPaused,
+        constraint = platform_config.fee_mint != Pubkey::default() @ TokenFeeNotConfigured
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [b"banned_words"],
+        bump
+    )]
+    pub banned_words: Account<'info, BannedWords>,
+
+    #[account(
+        mut,
+        token::mint = platform_config.fee_mint,
+        token::authority = creator
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = platform_config.fee_mint,
+        constraint = platform_token_account.owner == platform_config.fee_wallet @ InvalidFeeWallet
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::CreatorReplied;
+use crate::utils::validate_ipfs_cid;
+
+pub fn creator_reply(ctx: Context<CreatorReply>, new_ipfs_cid: String) -> Result<()> {
+    // Validation: Check IPFS CID is non-empty with a valid multibase prefix, length and alphabet
+    validate_ipfs_cid(
+        &new_ipfs_cid,
+        ctx.accounts.feedback_board.storage_kind,
+        &ctx.accounts.platform_config,
+    )?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // Unlike submit_feedback, only the owner or a co-creator may reply; no platform fee is charged
+    let old_ipfs_cid = std::mem::replace(&mut feedback_board.ipfs_cid, new_ipfs_cid);
+    feedback_board.content_hash = None;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+    feedback_board.reply_count = feedback_board
+        .reply_count
+        .checked_add(1)
+        .ok_or(ArithmeticOverflow)?;
+
+    msg!(
+        "Creator replied on board {}. Previous IPFS CID: {}. Updated IPFS CID: {}",
+        feedback_board.board_id,
+        old_ipfs_cid,
+        feedback_board.ipfs_cid
+    );
+
+    // Emit event
+    emit!(CreatorReplied {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        editor: ctx.accounts.owner.key(),
+        old_ipfs_cid,
+        new_ipfs_cid: feedback_board.ipfs_cid.clone(),
+        reply_count: feedback_board.reply_count,
+        timestamp: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreatorReply<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        constraint = feedback_board.can_post_as_creator(&owner.key()) @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion,
+        constraint = !feedback_board.is_frozen @ BoardFrozen
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    // The board owner or one of its co-creators
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeedbackItemDeleted;
+use crate::utils::validate_ipfs_cid;
+
+pub fn delete_feedback_item(
+    ctx: Context<DeleteFeedbackItem>,
+    new_ipfs_cid: String,
+    item_ref: String,
+) -> Result<()> {
+    // Validation: Check the item reference identifies an item within the IPFS payload
+    if item_ref.trim().is_empty() || item_ref.len() > FeedbackBoard::MAX_ITEM_REF_LEN {
+        return Err(InvalidItemRef.into());
+    }
+
+    // Validation: Check IPFS CID is non-empty with a valid multibase prefix, length and alphabet
+    validate_ipfs_cid(
+        &new_ipfs_cid,
+        ctx.accounts.feedback_board.storage_kind,
+        &ctx.accounts.platform_config,
+    )?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // The item is removed from the payload off-chain; the new CID points at what remains
+    let old_ipfs_cid = std::mem::replace(&mut feedback_board.ipfs_cid, new_ipfs_cid);
+    feedback_board.content_hash = None;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+    feedback_board.deleted_count = feedback_board
+        .deleted_count
+        .checked_add(1)
+        .ok_or(ArithmeticOverflow)?;
+
+    msg!(
+        "Feedback item {} deleted from board {}. Previous IPFS CID: {}. Updated IPFS CID: {}",
+        item_ref,
+        feedback_board.board_id,
+        old_ipfs_cid,
+        feedback_board.ipfs_cid
+    );
+
+    // Emit event
+    emit!(FeedbackItemDeleted {
+        board_id: feedback_board.board_id.clone(),
+        item_ref,
+        owner: feedback_board.owner,
+        old_ipfs_cid,
+        new_ipfs_cid: feedback_board.ipfs_cid.clone(),
+        deleted_count: feedback_board.deleted_count,
+        timestamp: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeleteFeedbackItem<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion,
+        constraint = !feedback_board.is_frozen @ BoardFrozen
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+
+use crate::types::{BountyVault, FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BountyDeposited;
+
+pub fn deposit_bounty(ctx: Context<DepositBounty>, amount: u64) -> Result<()> {
+    let bounty_remaining = ctx
+        .accounts
+        .bounty_vault
+        .bounty_remaining
+        .checked_add(amount)
+        .ok_or(ArithmeticOverflow)?;
+
+    // Transfer the bounty into the board's vault via CPI
+    let ix = transfer(
+        &ctx.accounts.owner.key(),
+        &ctx.accounts.bounty_vault.key(),
+        amount,
+    );
+
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.bounty_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let bounty_vault = &mut ctx.accounts.bounty_vault;
+
+    // First deposit for this board initializes its vault
+    bounty_vault.board = ctx.accounts.feedback_board.key();
+    bounty_vault.bounty_remaining = bounty_remaining;
+
+    msg!(
+        "Deposited {} lamports of bounty for feedback board {}",
+        amount,
+        ctx.accounts.feedback_board.board_id
+    );
+
+    // Emit event
+    emit!(BountyDeposited {
+        board_id: ctx.accounts.feedback_board.board_id.clone(),
+        depositor: ctx.accounts.owner.key(),
+        amount,
+        bounty_remaining: bounty_vault.bounty_remaining,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositBounty<'info> {
+    #[account(
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + 32 + 8, // discriminator + board + bounty_remaining
+        seeds = [b"bounty_vault", feedback_board.key().as_ref()],
+        bump
+    )]
+    pub bounty_vault: Account<'info, BountyVault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{BoardCounter, PlatformConfig, Treasury};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::PlatformStats;
+
+// Read-only: reports top-line platform numbers in a single event for dashboards
+pub fn emit_platform_stats(ctx: Context<EmitPlatformStats>) -> Result<()> {
+    let total_boards = ctx.accounts.board_counter.total;
+    let treasury_balance = ctx.accounts.treasury.to_account_info().lamports();
+    let total_fees = ctx.accounts.platform_config.total_fees;
+
+    msg!(
+        "Platform stats: {} boards, {} lamports in the treasury, {} lamports of fees collected",
+        total_boards,
+        treasury_balance,
+        total_fees
+    );
+
+    // Emit event
+    emit!(PlatformStats {
+        total_boards,
+        treasury_balance,
+        total_fees,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EmitPlatformStats<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"board_counter"],
+        bump
+    )]
+    pub board_counter: Account<'info, BoardCounter>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
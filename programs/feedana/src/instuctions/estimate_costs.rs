@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{
+    CostOperation, CreatorBoardIndex, CreatorStats, FeedbackBoard, PlatformConfig,
+    SubmitterRecord,
+};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::EstimatedCosts;
+
+// Read-only: prices an operation for `payer` from the current fees and rent so clients
+// can show a cost preview with simulateTransaction. The network fee isn't known
+// on-chain; clients add it from getFeeForMessage.
+pub fn estimate_costs(ctx: Context<EstimateCosts>, operation: CostOperation) -> Result<()> {
+    let platform_config = &ctx.accounts.platform_config;
+    let payer = ctx.accounts.payer.key();
+    let rent = Rent::get()?;
+
+    let (platform_fee, rent_required) = match operation {
+        CostOperation::CreateBoard => {
+            // The board is always new; creator stats are created on a creator's first board
+            let mut rent_required = rent.minimum_balance(FeedbackBoard::SPACE);
+
+            if ctx.accounts.creator_stats.data_is_empty() {
+                rent_required = rent_required
+                    .checked_add(rent.minimum_balance(CreatorStats::SPACE))
+                    .ok_or(ArithmeticOverflow)?;
+            }
+
+            // The creator's board index grows by one entry, topped up to rent exemption
+            let index_info = &ctx.accounts.creator_board_index;
+            let index_space = if index_info.data_is_empty() {
+                CreatorBoardIndex::space_for(1)
+            } else {
+                index_info.data_len() + 32
+            };
+            let index_top_up = rent
+                .minimum_balance(index_space)
+                .saturating_sub(index_info.lamports());
+            rent_required = rent_required.checked_add(index_top_up).ok_or(ArithmeticOverflow)?;
+
+            (platform_config.create_board_fee, rent_required)
+        }
+        CostOperation::SubmitFeedback => {
+            // Fee-exempt wallets submit for free
+            let platform_fee = if platform_config.fee_exempt.contains(&payer) {
+                0
+            } else {
+                platform_config.submit_fee
+            };
+
+            // The submitter record is created on the giver's first submit_feedback
+            let rent_required = if ctx.accounts.submitter_record.data_is_empty() {
+                rent.minimum_balance(SubmitterRecord::SPACE)
+            } else {
+                0
+            };
+
+            (platform_fee, rent_required)
+        }
+        CostOperation::BatchSubmitFeedback { item_count } => {
+            let platform_fee = platform_config
+                .submit_fee
+                .checked_mul(u64::from(item_count))
+                .ok_or(ArithmeticOverflow)?;
+
+            (platform_fee, 0)
+        }
+    };
+
+    let total = platform_fee.checked_add(rent_required).ok_or(ArithmeticOverflow)?;
+
+    msg!(
+        "Estimated cost: {} lamports platform fee + {} lamports rent = {} lamports",
+        platform_fee,
+        rent_required,
+        total
+    );
+
+    // Emit event
+    emit!(EstimatedCosts {
+        payer,
+        operation,
+        platform_fee,
+        rent_required,
+        total,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EstimateCosts<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub payer: Signer<'info>,
+
+    /// CHECK: Only checked for existence to price its creation
+    #[account(
+        seeds = [b"creator_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: UncheckedAccount<'info>,
+
+    /// CHECK: Only its size and balance are read to price its growth
+    #[account(
+        seeds = [b"creator_board_index", payer.key().as_ref()],
+        bump
+    )]
+    pub creator_board_index: UncheckedAccount<'info>,
+
+    /// CHECK: Only checked for existence to price its creation
+    #[account(
+        seeds = [b"submitter_record", payer.key().as_ref()],
+        bump
+    )]
+    pub submitter_record: UncheckedAccount<'info>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BoardFrozen as BoardFrozenEvent;
+use crate::utils::check_cosigner;
+
+// Irreversible by design: no instruction clears is_frozen, so the current CID is the
+// board's final snapshot
+pub fn freeze_board_cid(ctx: Context<FreezeBoardCid>) -> Result<()> {
+    // Validation: Check the board's cosigner, if any, also signed
+    check_cosigner(&ctx.accounts.feedback_board, ctx.accounts.cosigner.as_ref())?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    feedback_board.is_frozen = true;
+    feedback_board.status = feedback_board.current_status();
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Feedback board {} frozen at IPFS CID: {}",
+        feedback_board.board_id,
+        feedback_board.ipfs_cid
+    );
+
+    // Emit event
+    emit!(BoardFrozenEvent {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        ipfs_cid: feedback_board.ipfs_cid.clone(),
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FreezeBoardCid<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion,
+        constraint = !feedback_board.is_frozen @ BoardFrozen
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Required only when the board has a cosigner
+    pub cosigner: Option<Signer<'info>>,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::types::FeedbackBoard;
+use crate::events::BoardStateReported;
+
+// Read-only: reports the board through an event so clients can read it from
+// simulateTransaction logs without decoding the account themselves
+pub fn get_board_state(ctx: Context<GetBoardState>) -> Result<()> {
+    let feedback_board = &ctx.accounts.feedback_board;
+
+    msg!("Reporting state of feedback board {}", feedback_board.board_id);
+
+    // Emit event
+    emit!(BoardStateReported {
+        creator: feedback_board.creator,
+        status: feedback_board.status,
+        owner: feedback_board.owner,
+        board_id: feedback_board.board_id.clone(),
+        ipfs_cid: feedback_board.ipfs_cid.clone(),
+        title: feedback_board.title.clone(),
+        bump: feedback_board.bump,
+        created_at: feedback_board.created_at,
+        updated_at: feedback_board.updated_at,
+        index: feedback_board.index,
+        gate_mint: feedback_board.gate_mint,
+        gate_min_balance: feedback_board.gate_min_balance,
+        collection_mint: feedback_board.collection_mint,
+        feedback_seq: feedback_board.feedback_seq,
+        last_submission_ts: feedback_board.last_submission_ts,
+        min_submission_interval: feedback_board.min_submission_interval,
+        expires_at: feedback_board.expires_at,
+        blocked: feedback_board.blocked.clone(),
+        allowlist_only: feedback_board.allowlist_only,
+        allowed: feedback_board.allowed.clone(),
+        max_items: feedback_board.max_items,
+        item_count: feedback_board.item_count,
+        min_content_bytes: feedback_board.min_content_bytes,
+        is_paused: feedback_board.is_paused,
+        is_frozen: feedback_board.is_frozen,
+        is_listed: feedback_board.is_listed,
+        creator_verified: feedback_board.creator_verified,
+        tags: feedback_board.tags.clone(),
+        report_count: feedback_board.report_count,
+        reply_count: feedback_board.reply_count,
+        deleted_count: feedback_board.deleted_count,
+        collected_fees: feedback_board.collected_fees,
+        total_interactions: feedback_board.total_interactions,
+        allowed_relayer: feedback_board.allowed_relayer,
+        cosigner: feedback_board.cosigner,
+        co_creators: feedback_board.co_creators.clone(),
+        content_hash: feedback_board.content_hash,
+        storage_kind: feedback_board.storage_kind,
+        encrypted: feedback_board.encrypted,
+        encryption_scheme: feedback_board.encryption_scheme.clone(),
+        schema_version: feedback_board.schema_version,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GetBoardState<'info> {
+    #[account(
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::types::FeedbackBoard;
+use crate::events::BoardStats;
+
+// Read-only: reports the board's activity counters for leaderboard ranking, a much
+// smaller payload than get_board_state
+pub fn get_board_stats(ctx: Context<GetBoardStats>) -> Result<()> {
+    let feedback_board = &ctx.accounts.feedback_board;
+
+    msg!(
+        "Feedback board {} has {} interactions",
+        feedback_board.board_id,
+        feedback_board.total_interactions
+    );
+
+    // Emit event
+    emit!(BoardStats {
+        board_id: feedback_board.board_id.clone(),
+        creator: feedback_board.creator,
+        feedback_seq: feedback_board.feedback_seq,
+        item_count: feedback_board.item_count,
+        total_interactions: feedback_board.total_interactions,
+        last_submission_ts: feedback_board.last_submission_ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GetBoardStats<'info> {
+    #[account(
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+}
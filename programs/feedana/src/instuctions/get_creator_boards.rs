@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::types::CreatorBoardIndex;
+use crate::events::CreatorBoardsReported;
+
+// Read-only: reports a creator's boards through an event so clients can list them
+// from simulateTransaction logs
+pub fn get_creator_boards(ctx: Context<GetCreatorBoards>) -> Result<()> {
+    let creator_board_index = &ctx.accounts.creator_board_index;
+
+    msg!(
+        "Reporting {} boards of creator {}",
+        creator_board_index.boards.len(),
+        creator_board_index.creator
+    );
+
+    // Emit event
+    emit!(CreatorBoardsReported {
+        creator: creator_board_index.creator,
+        boards: creator_board_index.boards.clone(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GetCreatorBoards<'info> {
+    #[account(
+        seeds = [b"creator_board_index", creator_board_index.creator.as_ref()],
+        bump
+    )]
+    pub creator_board_index: Account<'info, CreatorBoardIndex>,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, FeedbackEntry};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeedbackEntryReported;
+
+// Read-only: reports entries start..start + count through events, for log-based paging.
+// Entry N lives at ["entry", board, N as u64 LE], so clients derive the addresses and pass
+// them as remaining accounts in order. Seqs taken by overwrite-mode submissions have no
+// entry; those addresses hold no data and are skipped.
+pub fn get_entry_range(ctx: Context<GetEntryRange>, start: u64, count: u8) -> Result<()> {
+    let feedback_board = &ctx.accounts.feedback_board;
+    let board_key = feedback_board.key();
+
+    // Validation: Check the range is non-empty, bounded and below the next seq
+    let end = start.checked_add(u64::from(count)).ok_or(ArithmeticOverflow)?;
+    if count == 0
+        || count > FeedbackEntry::MAX_ENTRY_RANGE
+        || end > feedback_board.feedback_seq
+        || ctx.remaining_accounts.len() != usize::from(count)
+    {
+        return Err(InvalidEntryRange.into());
+    }
+
+    let mut reported = 0u8;
+
+    for (seq, entry_info) in (start..end).zip(ctx.remaining_accounts.iter()) {
+        // An empty account has no stored bump to verify its address with. Skipping it only
+        // leaves that seq out of the caller's own read, so empty accounts aren't checked.
+        if entry_info.data_is_empty() {
+            continue;
+        }
+
+        if entry_info.owner != &crate::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+
+        let entry = FeedbackEntry::try_deserialize(&mut &entry_info.try_borrow_data()?[..])?;
+
+        // Validation: Check each account is the entry PDA for its seq, using the stored bump
+        // rather than a bump search per entry
+        let expected_address = Pubkey::create_program_address(
+            &[b"entry", board_key.as_ref(), &seq.to_le_bytes(), &[entry.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| ErrorCode::ConstraintSeeds)?;
+
+        if entry_info.key() != expected_address {
+            return Err(ErrorCode::ConstraintSeeds.into());
+        }
+
+        // Emit event
+        emit!(FeedbackEntryReported {
+            board_id: feedback_board.board_id.clone(),
+            entry: entry_info.key(),
+            seq: entry.seq,
+            ipfs_cid: entry.ipfs_cid,
+            author: entry.author,
+            timestamp: entry.timestamp,
+        });
+
+        reported += 1;
+    }
+
+    msg!(
+        "Reported {} of {} entries from seq {} on board {}",
+        reported,
+        count,
+        start,
+        feedback_board.board_id
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GetEntryRange<'info> {
+    #[account(
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{BannedWords, BoardCounter, FeedbackBoard, PlatformConfig, Treasury};
+use crate::events::PlatformConfigInitialized;
+use crate::utils::validate_wallet;
+
+// Launch pricing, tunable afterwards via update_fees
+const DEFAULT_CREATE_BOARD_FEE: u64 = 10;
+const DEFAULT_SUBMIT_FEE: u64 = 1;
+const DEFAULT_MAX_BOARDS_PER_CREATOR: u64 = 100;
+
+pub fn initialize_platform_config(
+    ctx: Context<InitializePlatformConfig>,
+    fee_wallet: Pubkey,
+) -> Result<()> {
+    // Validation: Check the fee wallet is a real wallet
+    validate_wallet(&fee_wallet)?;
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    // Initialize platform config
+    platform_config.admin = ctx.accounts.admin.key();
+    platform_config.fee_wallet = fee_wallet;
+    platform_config.create_board_fee = DEFAULT_CREATE_BOARD_FEE;
+    platform_config.submit_fee = DEFAULT_SUBMIT_FEE;
+    platform_config.max_boards_per_creator = DEFAULT_MAX_BOARDS_PER_CREATOR;
+    // Token fees stay disabled until the admin configures a mint
+    platform_config.fee_mint = Pubkey::default();
+    platform_config.token_fee_amount = 0;
+    platform_config.program_paused = false;
+    platform_config.total_fees = 0;
+    // Board IDs may use the full PDA seed length until the admin lowers the limit
+    platform_config.max_board_id_len = FeedbackBoard::MAX_BOARD_ID_LEN as u8;
+    platform_config.pending_admin = None;
+    platform_config.fee_exempt = Vec::new();
+    platform_config.cid_min_len = PlatformConfig::DEFAULT_CID_MIN_LEN;
+    platform_config.cid_max_len = PlatformConfig::DEFAULT_CID_MAX_LEN;
+    platform_config.is_initialized = true;
+    platform_config.pending_fee_change = None;
+    // Tips go entirely to the creator until the admin sets a platform share
+    platform_config.tip_fee_bps = 0;
+
+    // Board indices start at 0
+    ctx.accounts.board_counter.total = 0;
+
+    // No board IDs are reserved until the admin adds them
+    ctx.accounts.banned_words.words = Vec::new();
+
+    msg!(
+        "Platform config initialized with fee wallet: {}",
+        platform_config.fee_wallet
+    );
+
+    // Emit event
+    emit!(PlatformConfigInitialized {
+        admin: platform_config.admin,
+        fee_wallet: platform_config.fee_wallet,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    // `init` on a fixed seed means this can only ever succeed once
+    #[account(
+        init,
+        payer = admin,
+        // discriminator + admin + fee_wallet + create_board_fee + submit_fee
+        // + max_boards_per_creator + fee_mint + token_fee_amount + program_paused + total_fees
+        // + max_board_id_len + pending_admin + fee_exempt + cid_min_len + cid_max_len
+        // + is_initialized + pending_fee_change + tip_fee_bps
+        space = 8 + 32 + 32 + 8 + 8 + 8 + 32 + 8 + 1 + 8 + 1 + (1 + 32)
+            + (4 + 32 * PlatformConfig::MAX_FEE_EXEMPT_WALLETS) + 1 + 1 + 1 + (1 + 8 + 8 + 32 + 8 + 2 + 8) + 2,
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 8, // discriminator + total
+        seeds = [b"board_counter"],
+        bump
+    )]
+    pub board_counter: Account<'info, BoardCounter>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8, // discriminator
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = BannedWords::SPACE,
+        seeds = [b"banned_words"],
+        bump
+    )]
+    pub banned_words: Account<'info, BannedWords>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+use anchor_lang::Discriminator;
+
+use crate::types::{
+    BoardCounter, CreatorBoardIndex, CreatorStats, FeedbackBoard, LegacyFeedbackBoard,
+    PlatformConfig, StorageKind,
+};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BoardMigrated;
+use crate::instuctions::create_board::append_to_creator_board_index;
+
+pub fn migrate_board(ctx: Context<MigrateBoard>) -> Result<()> {
+    let board_info = ctx.accounts.feedback_board.to_account_info();
+
+    // Boards already on the current layout are left untouched
+    let current = FeedbackBoard::try_deserialize(&mut &board_info.try_borrow_data()?[..]);
+    if let Ok(feedback_board) = current {
+        if feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION {
+            msg!(
+                "Feedback board {} is already on schema version {}",
+                feedback_board.board_id,
+                feedback_board.schema_version
+            );
+            return Ok(());
+        }
+    }
+
+    // Validation: Check the account holds a board in the original layout
+    let legacy = {
+        let data = board_info.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != *FeedbackBoard::DISCRIMINATOR {
+            return Err(UnrecognizedBoardLayout.into());
+        }
+        LegacyFeedbackBoard::deserialize(&mut &data[8..]).map_err(|_| UnrecognizedBoardLayout)?
+    };
+
+    // Validation: Check only the board's creator can migrate it
+    if legacy.creator != ctx.accounts.creator.key() {
+        return Err(UnauthorizedAccess.into());
+    }
+
+    // Validation: Check the account is the board's PDA
+    let (expected_address, bump) = Pubkey::find_program_address(
+        &[b"feedback_board", legacy.creator.as_ref(), legacy.board_id.as_bytes()],
+        ctx.program_id,
+    );
+    if board_info.key() != expected_address {
+        return Err(ErrorCode::ConstraintSeeds.into());
+    }
+
+    // Top up rent for the larger account before growing it
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(FeedbackBoard::SPACE);
+    let top_up = rent_exempt_minimum.saturating_sub(board_info.lamports());
+    if top_up > 0 {
+        let ix = transfer(&ctx.accounts.creator.key(), &board_info.key(), top_up);
+
+        invoke(
+            &ix,
+            &[
+                ctx.accounts.creator.to_account_info(),
+                board_info.clone(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    board_info.resize(FeedbackBoard::SPACE)?;
+
+    // Count the board towards its creator like a newly created one. The per-creator cap
+    // isn't enforced, since the board already exists and would otherwise stay on the old
+    // layout
+    let creator_stats = &mut ctx.accounts.creator_stats;
+    creator_stats.creator = legacy.creator;
+    creator_stats.board_count = creator_stats
+        .board_count
+        .checked_add(1)
+        .ok_or(ArithmeticOverflow)?;
+
+    append_to_creator_board_index(
+        &mut ctx.accounts.creator_board_index,
+        &ctx.accounts.creator,
+        &ctx.accounts.system_program,
+        board_info.key(),
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let board_counter = &mut ctx.accounts.board_counter;
+
+    // Back-fill every field added since the original layout
+    let feedback_board = FeedbackBoard {
+        creator: legacy.creator,
+        status: FeedbackBoard::STATUS_ACTIVE,
+        ipfs_cid: legacy.ipfs_cid,
+        // Legacy boards have no title, so the board ID stands in until the creator sets one
+        title: legacy.board_id.clone(),
+        board_id: legacy.board_id,
+        bump,
+        owner: legacy.creator,
+        created_at: now,
+        updated_at: now,
+        index: board_counter.total,
+        gate_mint: None,
+        gate_min_balance: 0,
+        collection_mint: None,
+        feedback_seq: 0,
+        last_submission_ts: 0,
+        min_submission_interval: 0,
+        expires_at: None,
+        blocked: Vec::new(),
+        allowlist_only: false,
+        allowed: Vec::new(),
+        max_items: 0,
+        item_count: 0,
+        min_content_bytes: 0,
+        is_paused: false,
+        is_frozen: false,
+        is_listed: true,
+        creator_verified: ctx.accounts.creator_stats.verified,
+        migrated: true,
+        tags: Vec::new(),
+        report_count: 0,
+        reply_count: 0,
+        deleted_count: 0,
+        collected_fees: 0,
+        total_interactions: 0,
+        allowed_relayer: None,
+        cosigner: None,
+        co_creators: Vec::new(),
+        content_hash: None,
+        // Every board predating storage kinds holds IPFS CIDs
+        storage_kind: StorageKind::Ipfs,
+        encrypted: false,
+        encryption_scheme: None,
+        schema_version: FeedbackBoard::CURRENT_SCHEMA_VERSION,
+    };
+
+    // Migrated boards join the global creation order at the end
+    board_counter.total = board_counter
+        .total
+        .checked_add(1)
+        .ok_or(ArithmeticOverflow)?;
+
+    feedback_board.try_serialize(&mut &mut board_info.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Feedback board {} migrated to schema version {}",
+        feedback_board.board_id,
+        feedback_board.schema_version
+    );
+
+    // Emit event
+    emit!(BoardMigrated {
+        board_id: feedback_board.board_id,
+        creator: feedback_board.creator,
+        schema_version: feedback_board.schema_version,
+        index: feedback_board.index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateBoard<'info> {
+    /// CHECK: Legacy boards can't be deserialized as FeedbackBoard; the discriminator,
+    /// layout, creator and PDA address are verified in the handler
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    pub feedback_board: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorStats::SPACE,
+        seeds = [b"creator_stats", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorBoardIndex::space_for(0),
+        seeds = [b"creator_board_index", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_board_index: Account<'info, CreatorBoardIndex>,
+
+    #[account(
+        mut,
+        seeds = [b"board_counter"],
+        bump
+    )]
+    pub board_counter: Account<'info, BoardCounter>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
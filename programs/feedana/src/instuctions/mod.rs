@@ -1,5 +1,125 @@
+pub mod accept_admin;
+pub mod add_banned_word;
+pub mod add_co_creator;
+pub mod add_fee_exempt_wallet;
+pub mod allow_wallet;
+pub mod append_feedback;
+pub mod apply_fee_change;
+pub mod batch_submit_feedback;
+pub mod block_wallet;
+pub mod claim_bounty;
+pub mod correct_initial_cid;
 pub mod create_board;
+pub mod create_board_idempotent;
+pub mod create_board_with_token;
+pub mod creator_reply;
+pub mod delete_feedback_item;
+pub mod deposit_bounty;
+pub mod disallow_wallet;
+pub mod edit_board_metadata;
+pub mod emit_platform_stats;
+pub mod estimate_costs;
+pub mod freeze_board_cid;
+pub mod get_board_state;
+pub mod get_board_stats;
+pub mod get_creator_boards;
+pub mod get_entry_range;
+pub mod initialize_platform_config;
+pub mod migrate_board;
+pub mod pause_board;
+pub mod propose_admin;
+pub mod propose_fee_change;
+pub mod refund_contributor;
+pub mod remove_banned_word;
+pub mod remove_co_creator;
+pub mod remove_fee_exempt_wallet;
+pub mod report_feedback;
+pub mod resolve_board;
+pub mod resume_board;
+pub mod set_allowed_relayer;
+pub mod set_board_expiry;
+pub mod set_board_title;
+pub mod set_cid_length_range;
+pub mod set_cosigner;
+pub mod set_creator_verified;
+pub mod set_listed;
+pub mod set_max_board_id_len;
+pub mod set_max_boards_per_creator;
+pub mod set_max_items;
+pub mod set_program_paused;
+pub mod set_tags;
+pub mod set_tip_fee_bps;
 pub mod submit_feedback;
+pub mod submit_feedback_relayed;
+pub mod submit_feedback_with_tip;
+pub mod submit_feedback_with_token;
+pub mod transfer_ownership;
+pub mod unblock_wallet;
+pub mod update_fees;
+pub mod update_gating;
+pub mod update_token_fee;
+pub mod validate_cid_only;
+pub mod withdraw_treasury;
 
+pub use accept_admin::*;
+pub use add_banned_word::*;
+pub use add_co_creator::*;
+pub use add_fee_exempt_wallet::*;
+pub use allow_wallet::*;
+pub use append_feedback::*;
+pub use apply_fee_change::*;
+pub use batch_submit_feedback::*;
+pub use block_wallet::*;
+pub use claim_bounty::*;
+pub use correct_initial_cid::*;
 pub use create_board::*;
+pub use create_board_idempotent::*;
+pub use create_board_with_token::*;
+pub use creator_reply::*;
+pub use delete_feedback_item::*;
+pub use deposit_bounty::*;
+pub use disallow_wallet::*;
+pub use edit_board_metadata::*;
+pub use emit_platform_stats::*;
+pub use estimate_costs::*;
+pub use freeze_board_cid::*;
+pub use get_board_state::*;
+pub use get_board_stats::*;
+pub use get_creator_boards::*;
+pub use get_entry_range::*;
+pub use initialize_platform_config::*;
+pub use migrate_board::*;
+pub use pause_board::*;
+pub use propose_admin::*;
+pub use propose_fee_change::*;
+pub use refund_contributor::*;
+pub use remove_banned_word::*;
+pub use remove_co_creator::*;
+pub use remove_fee_exempt_wallet::*;
+pub use report_feedback::*;
+pub use resolve_board::*;
+pub use resume_board::*;
+pub use set_allowed_relayer::*;
+pub use set_board_expiry::*;
+pub use set_board_title::*;
+pub use set_cid_length_range::*;
+pub use set_cosigner::*;
+pub use set_creator_verified::*;
+pub use set_listed::*;
+pub use set_max_board_id_len::*;
+pub use set_max_boards_per_creator::*;
+pub use set_max_items::*;
+pub use set_program_paused::*;
+pub use set_tags::*;
+pub use set_tip_fee_bps::*;
 pub use submit_feedback::*;
+pub use submit_feedback_relayed::*;
+pub use submit_feedback_with_tip::*;
+pub use submit_feedback_with_token::*;
+pub use transfer_ownership::*;
+pub use unblock_wallet::*;
+pub use update_fees::*;
+pub use update_gating::*;
+pub use update_token_fee::*;
+pub use validate_cid_only::*;
+pub use withdraw_treasury::*;
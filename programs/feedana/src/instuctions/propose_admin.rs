@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::AdminTransferProposed;
+use crate::utils::validate_wallet;
+
+// First step of an admin rotation; nothing changes until the new key signs accept_admin.
+// Proposing None cancels a pending rotation.
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+    // Validation: Check a newly set admin is a real wallet
+    if let Some(wallet) = new_admin.as_ref() {
+        validate_wallet(wallet)?;
+    }
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    platform_config.pending_admin = new_admin;
+
+    msg!(
+        "Admin transfer proposed to {:?}",
+        platform_config.pending_admin
+    );
+
+    // Emit event
+    emit!(AdminTransferProposed {
+        admin: platform_config.admin,
+        pending_admin: platform_config.pending_admin,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{PendingFeeChange, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeeChangeProposed;
+use crate::instuctions::update_fees::MAX_PLATFORM_FEE;
+use crate::instuctions::update_token_fee::MAX_TOKEN_FEE;
+
+// Step one of a timelocked fee change, the only way to raise any fee. The proposal sets
+// every fee at once, and a new one replaces any pending proposal and restarts the delay.
+pub fn propose_fee_change(
+    ctx: Context<ProposeFeeChange>,
+    create_board_fee: u64,
+    submit_fee: u64,
+    fee_mint: Pubkey,
+    token_fee_amount: u64,
+    tip_fee_bps: u16,
+) -> Result<()> {
+    // Validation: Check that no individual fee exceeds the cap
+    if create_board_fee > MAX_PLATFORM_FEE
+        || submit_fee > MAX_PLATFORM_FEE
+        || token_fee_amount > MAX_TOKEN_FEE
+    {
+        return Err(FeeTooHigh.into());
+    }
+
+    // Validation: Check the tip share is at most the whole tip
+    if tip_fee_bps > PlatformConfig::MAX_TIP_FEE_BPS {
+        return Err(InvalidTipFeeBps.into());
+    }
+
+    let effective_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(PlatformConfig::FEE_CHANGE_DELAY)
+        .ok_or(ArithmeticOverflow)?;
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    platform_config.pending_fee_change = Some(PendingFeeChange {
+        create_board_fee,
+        submit_fee,
+        fee_mint,
+        token_fee_amount,
+        tip_fee_bps,
+        effective_at,
+    });
+
+    msg!(
+        "Fee change proposed. Create board: {}, submit feedback: {}, token fee: {} of mint {}, \
+         tip fee: {} bps, effective at {}",
+        create_board_fee,
+        submit_fee,
+        token_fee_amount,
+        fee_mint,
+        tip_fee_bps,
+        effective_at
+    );
+
+    // Emit event
+    emit!(FeeChangeProposed {
+        admin: platform_config.admin,
+        create_board_fee,
+        submit_fee,
+        fee_mint,
+        token_fee_amount,
+        tip_fee_bps,
+        effective_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeFeeChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
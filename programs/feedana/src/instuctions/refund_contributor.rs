@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::ContributorRefunded;
+
+// Goodwill refund of submit fees, paid from the owner's own wallet - the fees themselves
+// stay in the treasury. collected_fees caps the total refunded across contributors.
+pub fn refund_contributor(ctx: Context<RefundContributor>, amount: u64) -> Result<()> {
+    // Validation: Check the refund fits within the fees this board has collected
+    if amount > ctx.accounts.feedback_board.collected_fees {
+        return Err(RefundExceedsCollectedFees.into());
+    }
+
+    // Transfer the refund via CPI
+    let ix = transfer(
+        &ctx.accounts.owner.key(),
+        &ctx.accounts.contributor.key(),
+        amount,
+    );
+
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.contributor.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    feedback_board.collected_fees = feedback_board
+        .collected_fees
+        .checked_sub(amount)
+        .ok_or(ArithmeticOverflow)?;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Refunded {} lamports to {} on board {}",
+        amount,
+        ctx.accounts.contributor.key(),
+        feedback_board.board_id
+    );
+
+    // Emit event
+    emit!(ContributorRefunded {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        contributor: ctx.accounts.contributor.key(),
+        amount,
+        collected_fees: feedback_board.collected_fees,
+        timestamp: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundContributor<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Any account can receive lamports; the owner chooses the contributor
+    #[account(mut)]
+    pub contributor: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{BannedWords, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BannedWordsUpdated;
+
+pub fn remove_banned_word(ctx: Context<RemoveBannedWord>, word: String) -> Result<()> {
+    let word = word.trim().to_lowercase();
+    let banned_words = &mut ctx.accounts.banned_words;
+
+    // Removing a word that isn't banned is a no-op
+    banned_words.words.retain(|banned| *banned != word);
+
+    msg!("Board ID {} is no longer reserved", word);
+
+    // Emit event
+    emit!(BannedWordsUpdated {
+        admin: ctx.accounts.admin.key(),
+        word,
+        banned: false,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveBannedWord<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"banned_words"],
+        bump
+    )]
+    pub banned_words: Account<'info, BannedWords>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::CoCreatorsUpdated;
+
+pub fn remove_co_creator(ctx: Context<RemoveCoCreator>, co_creator: Pubkey) -> Result<()> {
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // Removing a wallet that isn't a co-creator is a no-op
+    feedback_board.co_creators.retain(|existing| *existing != co_creator);
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Co-creator {} removed from feedback board {}",
+        co_creator,
+        feedback_board.board_id
+    );
+
+    // Emit event
+    emit!(CoCreatorsUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        co_creator,
+        added: false,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveCoCreator<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
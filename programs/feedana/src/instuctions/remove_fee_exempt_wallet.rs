@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeeExemptionUpdated;
+
+pub fn remove_fee_exempt_wallet(ctx: Context<RemoveFeeExemptWallet>, wallet: Pubkey) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    // Removing a wallet that isn't exempt is a no-op
+    platform_config.fee_exempt.retain(|exempt| *exempt != wallet);
+
+    msg!("Wallet {} pays the submit fee again", wallet);
+
+    // Emit event
+    emit!(FeeExemptionUpdated {
+        admin: platform_config.admin,
+        wallet,
+        exempt: false,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveFeeExemptWallet<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+
+use crate::types::{FeedbackBoard, PlatformConfig, Treasury};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeedbackReported;
+
+pub fn report_feedback(ctx: Context<ReportFeedback>, item_ref: String) -> Result<()> {
+    // Validation: Check the item reference identifies an item within the IPFS payload
+    if item_ref.trim().is_empty() || item_ref.len() > FeedbackBoard::MAX_ITEM_REF_LEN {
+        return Err(InvalidItemRef.into());
+    }
+
+    // Reports cost the same platform fee as a feedback submission
+    let platform_fee = ctx.accounts.platform_config.submit_fee;
+
+    // Transfer platform fee via CPI
+    let ix = transfer(
+        &ctx.accounts.reporter.key(),
+        &ctx.accounts.treasury.key(),
+        platform_fee,
+    );
+
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.reporter.to_account_info(),
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    ctx.accounts.platform_config.record_fee(platform_fee)?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    feedback_board.report_count = feedback_board
+        .report_count
+        .checked_add(1)
+        .ok_or(ArithmeticOverflow)?;
+
+    msg!(
+        "Feedback item {} reported on board {}",
+        item_ref,
+        feedback_board.board_id
+    );
+
+    // Emit event
+    emit!(FeedbackReported {
+        board_id: feedback_board.board_id.clone(),
+        item_ref,
+        reporter: ctx.accounts.reporter.key(),
+        report_count: feedback_board.report_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReportFeedback<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.is_initialized @ ConfigNotInitialized,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::events::BoardResolved;
+use crate::utils::validate_board_id;
+
+// Read-only: derives a board's PDA from its creator and board_id, for clients that would
+// rather simulate this than reimplement the derivation. The board need not exist.
+pub fn resolve_board(ctx: Context<ResolveBoard>, creator: Pubkey, board_id: String) -> Result<()> {
+    // Validation: Check board_id with the same rules as board creation
+    validate_board_id(&board_id, &ctx.accounts.platform_config)?;
+
+    let (address, bump) = Pubkey::find_program_address(
+        &[b"feedback_board", creator.as_ref(), board_id.as_bytes()],
+        ctx.program_id,
+    );
+
+    msg!("Feedback board {} of {} resolves to {}", board_id, creator, address);
+
+    // Emit event
+    emit!(BoardResolved {
+        creator,
+        board_id,
+        address,
+        bump,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveBoard<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
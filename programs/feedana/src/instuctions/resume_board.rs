@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BoardResumed;
+
+pub fn resume_board(ctx: Context<ResumeBoard>) -> Result<()> {
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    feedback_board.is_paused = false;
+    feedback_board.status = feedback_board.current_status();
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Feedback board {} resumed", feedback_board.board_id);
+
+    // Emit event
+    emit!(BoardResumed {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResumeBoard<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
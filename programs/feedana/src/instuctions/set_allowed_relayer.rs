@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::AllowedRelayerUpdated;
+use crate::utils::validate_wallet;
+
+pub fn set_allowed_relayer(
+    ctx: Context<SetAllowedRelayer>,
+    allowed_relayer: Option<Pubkey>,
+) -> Result<()> {
+    // Validation: Check a newly set relayer is a real wallet
+    if let Some(wallet) = allowed_relayer.as_ref() {
+        validate_wallet(wallet)?;
+    }
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // None disables relayed submissions to this board
+    feedback_board.allowed_relayer = allowed_relayer;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Feedback board {} allowed relayer set to {:?}",
+        feedback_board.board_id,
+        feedback_board.allowed_relayer
+    );
+
+    // Emit event
+    emit!(AllowedRelayerUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        allowed_relayer: feedback_board.allowed_relayer,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAllowedRelayer<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
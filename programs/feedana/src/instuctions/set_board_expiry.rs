@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BoardExpiryUpdated;
+
+pub fn set_board_expiry(ctx: Context<SetBoardExpiry>, expires_at: Option<i64>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validation: Check a new expiry lies in the future; None clears it
+    if expires_at.is_some_and(|expires_at| expires_at <= now) {
+        return Err(InvalidExpiry.into());
+    }
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    feedback_board.expires_at = expires_at;
+    feedback_board.updated_at = now;
+
+    msg!(
+        "Feedback board {} expiry updated to {:?}",
+        feedback_board.board_id,
+        feedback_board.expires_at
+    );
+
+    // Emit event
+    emit!(BoardExpiryUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        expires_at: feedback_board.expires_at,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBoardExpiry<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
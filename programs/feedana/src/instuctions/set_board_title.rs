@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BoardTitleUpdated;
+use crate::utils::validate_board_title;
+
+pub fn set_board_title(ctx: Context<SetBoardTitle>, title: String) -> Result<()> {
+    // Validation: Check the title is non-empty and fits in the account
+    validate_board_title(&title)?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    feedback_board.title = title;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Feedback board {} title updated to {}",
+        feedback_board.board_id,
+        feedback_board.title
+    );
+
+    // Emit event
+    emit!(BoardTitleUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        title: feedback_board.title.clone(),
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBoardTitle<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::CidLengthRangeUpdated;
+
+pub fn set_cid_length_range(
+    ctx: Context<SetCidLengthRange>,
+    cid_min_len: u8,
+    cid_max_len: u8,
+) -> Result<()> {
+    // Validation: Check the range is non-empty and fits in the space reserved for ipfs_cid
+    if cid_min_len == 0
+        || cid_min_len > cid_max_len
+        || usize::from(cid_max_len) > FeedbackBoard::MAX_CID_LEN
+    {
+        return Err(InvalidCidLengthRange.into());
+    }
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    platform_config.cid_min_len = cid_min_len;
+    platform_config.cid_max_len = cid_max_len;
+
+    msg!(
+        "CID length range updated to {}..={}",
+        platform_config.cid_min_len,
+        platform_config.cid_max_len
+    );
+
+    // Emit event
+    emit!(CidLengthRangeUpdated {
+        admin: platform_config.admin,
+        cid_min_len: platform_config.cid_min_len,
+        cid_max_len: platform_config.cid_max_len,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCidLengthRange<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
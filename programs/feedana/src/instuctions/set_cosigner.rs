@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::CosignerUpdated;
+use crate::utils::{check_cosigner, validate_wallet};
+
+pub fn set_cosigner(ctx: Context<SetCosigner>, cosigner: Option<Pubkey>) -> Result<()> {
+    // Validation: Check the current cosigner, if any, also signed so the owner can't
+    // drop two-party control alone
+    check_cosigner(&ctx.accounts.feedback_board, ctx.accounts.cosigner.as_ref())?;
+
+    // Validation: Check a newly set cosigner is a real wallet
+    if let Some(wallet) = cosigner.as_ref() {
+        validate_wallet(wallet)?;
+    }
+
+    // Validation: Check a new cosigner signed as well. Sensitive instructions need it from
+    // now on, so a mistyped key would lock them on this board for good.
+    if let Some(new_cosigner) = cosigner {
+        let accepted = ctx
+            .accounts
+            .incoming_cosigner
+            .as_ref()
+            .is_some_and(|incoming_cosigner| incoming_cosigner.key() == new_cosigner);
+        if !accepted {
+            return Err(IncomingCosignerNotSigned.into());
+        }
+    }
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // None returns the board to owner-only control
+    feedback_board.cosigner = cosigner;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Feedback board {} cosigner set to {:?}",
+        feedback_board.board_id,
+        feedback_board.cosigner
+    );
+
+    // Emit event
+    emit!(CosignerUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        cosigner: feedback_board.cosigner,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCosigner<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Required only when the board already has a cosigner
+    pub cosigner: Option<Signer<'info>>,
+
+    // Required only when setting a cosigner: the new cosigner's own signature
+    pub incoming_cosigner: Option<Signer<'info>>,
+}
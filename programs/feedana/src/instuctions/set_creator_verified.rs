@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{CreatorStats, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::CreatorVerificationChanged;
+use crate::utils::validate_wallet;
+
+// Boards copy the badge when they are created, so a change only shows on the creator's
+// later boards; existing boards keep the value they were created with
+pub fn set_creator_verified(
+    ctx: Context<SetCreatorVerified>,
+    creator: Pubkey,
+    verified: bool,
+) -> Result<()> {
+    // Validation: Check the creator is a real wallet
+    validate_wallet(&creator)?;
+
+    let creator_stats = &mut ctx.accounts.creator_stats;
+
+    // Creators can be verified before their first board, which then finds the stats in place
+    creator_stats.creator = creator;
+    creator_stats.verified = verified;
+
+    msg!("Creator {} verified: {}", creator, verified);
+
+    // Emit event
+    emit!(CreatorVerificationChanged {
+        admin: ctx.accounts.admin.key(),
+        creator,
+        verified,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct SetCreatorVerified<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CreatorStats::SPACE,
+        seeds = [b"creator_stats", creator.as_ref()],
+        bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BoardListingChanged;
+
+// Only changes discoverability; submissions are accepted either way
+pub fn set_listed(ctx: Context<SetListed>, is_listed: bool) -> Result<()> {
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    feedback_board.is_listed = is_listed;
+    feedback_board.status = feedback_board.current_status();
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Feedback board {} is now {}",
+        feedback_board.board_id,
+        if is_listed { "listed" } else { "unlisted" }
+    );
+
+    // Emit event
+    emit!(BoardListingChanged {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        is_listed: feedback_board.is_listed,
+        status: feedback_board.status,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetListed<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::MaxBoardIdLenUpdated;
+
+pub fn set_max_board_id_len(ctx: Context<SetMaxBoardIdLen>, max_board_id_len: u8) -> Result<()> {
+    // Validation: Check the limit leaves room for the minimum length and fits in a PDA seed
+    let limit = usize::from(max_board_id_len);
+    if !(FeedbackBoard::MIN_BOARD_ID_LEN..=FeedbackBoard::MAX_BOARD_ID_LEN).contains(&limit) {
+        return Err(InvalidBoardIdLimit.into());
+    }
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    platform_config.max_board_id_len = max_board_id_len;
+
+    msg!(
+        "Max board ID length updated to {}",
+        platform_config.max_board_id_len
+    );
+
+    // Emit event
+    emit!(MaxBoardIdLenUpdated {
+        admin: platform_config.admin,
+        max_board_id_len: platform_config.max_board_id_len,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMaxBoardIdLen<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
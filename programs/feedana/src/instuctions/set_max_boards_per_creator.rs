@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::MaxBoardsPerCreatorUpdated;
+
+pub fn set_max_boards_per_creator(
+    ctx: Context<SetMaxBoardsPerCreator>,
+    max_boards_per_creator: u64,
+) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    platform_config.max_boards_per_creator = max_boards_per_creator;
+
+    msg!(
+        "Max boards per creator updated to {}",
+        platform_config.max_boards_per_creator
+    );
+
+    // Emit event
+    emit!(MaxBoardsPerCreatorUpdated {
+        admin: platform_config.admin,
+        max_boards_per_creator: platform_config.max_boards_per_creator,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMaxBoardsPerCreator<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
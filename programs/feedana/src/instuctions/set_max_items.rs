@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::MaxItemsUpdated;
+
+pub fn set_max_items(ctx: Context<SetMaxItems>, max_items: u32) -> Result<()> {
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // Validation: Check a new cap still covers the items already submitted; 0 = unlimited
+    if max_items != 0 && max_items < feedback_board.item_count {
+        return Err(InvalidMaxItems.into());
+    }
+
+    feedback_board.max_items = max_items;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Feedback board {} max items set to {}",
+        feedback_board.board_id,
+        feedback_board.max_items
+    );
+
+    // Emit event
+    emit!(MaxItemsUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        max_items: feedback_board.max_items,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMaxItems<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
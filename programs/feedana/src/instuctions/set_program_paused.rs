@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::ProgramPauseUpdated;
+
+// Emergency switch: while set, every user instruction that writes state fails with
+// ProgramPaused. Reads and admin instructions stay available.
+pub fn set_program_paused(ctx: Context<SetProgramPaused>, program_paused: bool) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    platform_config.program_paused = program_paused;
+
+    msg!("Program paused set to {}", platform_config.program_paused);
+
+    // Emit event
+    emit!(ProgramPauseUpdated {
+        admin: platform_config.admin,
+        program_paused: platform_config.program_paused,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetProgramPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::TagsUpdated;
+use crate::utils::validate_tags;
+
+pub fn set_tags(ctx: Context<SetTags>, tags: Vec<String>) -> Result<()> {
+    // Validation: Check discovery tags are within the caps and character set
+    validate_tags(&tags)?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // Replaces the whole tag set; an empty list clears it
+    feedback_board.tags = tags;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Feedback board {} tags updated to {:?}",
+        feedback_board.board_id,
+        feedback_board.tags
+    );
+
+    // Emit event
+    emit!(TagsUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        tags: feedback_board.tags.clone(),
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTags<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::TipFeeUpdated;
+
+pub fn set_tip_fee_bps(ctx: Context<SetTipFeeBps>, tip_fee_bps: u16) -> Result<()> {
+    // Validation: Check the share is at most the whole tip
+    if tip_fee_bps > PlatformConfig::MAX_TIP_FEE_BPS {
+        return Err(InvalidTipFeeBps.into());
+    }
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    // Validation: Check the share doesn't go up; increases wait out the fee timelock instead
+    if tip_fee_bps > platform_config.tip_fee_bps {
+        return Err(FeeIncreaseRequiresTimelock.into());
+    }
+
+    platform_config.tip_fee_bps = tip_fee_bps;
+
+    msg!("Tip fee updated to {} bps", platform_config.tip_fee_bps);
+
+    // Emit event
+    emit!(TipFeeUpdated {
+        admin: platform_config.admin,
+        tip_fee_bps: platform_config.tip_fee_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTipFeeBps<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
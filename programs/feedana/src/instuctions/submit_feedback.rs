@@ -1,91 +1,284 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::system_instruction::transfer;
+use anchor_spl::token::TokenAccount;
 
-use crate::types::FeedbackBoard;
+use crate::types::{FeedbackBoard, PlatformConfig, SubmitterRecord, Treasury};
 use crate::errors::FeedbackBoardError::*;
 use crate::events::FeedbackSubmitted;
+use crate::utils::{
+    check_collection_gate, check_token_gate, load_feedback_board, store_feedback_board,
+    validate_ipfs_cid,
+};
 
-const PLATFORM_FEE_WALLET: &str = "96fN4Eegj84PaUcyEJrxUztDjo7Q7MySJzV2skLfgchY";
+pub fn submit_feedback(
+    ctx: Context<SubmitFeedback>,
+    new_ipfs_cid: String,
+    expected_current_cid: Option<String>,
+    content_hash: Option<[u8; 32]>,
+    content_bytes: Option<u32>,
+    nonce: u64,
+) -> Result<()> {
+    let feedback_giver = ctx.accounts.feedback_giver.key();
 
-pub fn submit_feedback(ctx: Context<SubmitFeedback>, new_ipfs_cid: String) -> Result<()> {
-    // Validation: Check if ipfs_cid is empty
-    if new_ipfs_cid.trim().is_empty() {
-        return Err(EmptyIpfsCid.into());
+    // Validation: Check the nonce is the giver's next one, so a captured transaction can't
+    // be replayed after it has landed
+    let submitter_record = &mut ctx.accounts.submitter_record;
+    if nonce != submitter_record.submission_nonce {
+        return Err(StaleNonce.into());
     }
 
-    // Validation: Check IPFS CID length (typical IPFS CIDs are 32-64 characters)
-    if new_ipfs_cid.len() < 32 || new_ipfs_cid.len() > 64 {
-        return Err(InvalidIpfsCidLength.into());
+    submitter_record.wallet = feedback_giver;
+    submitter_record.bump = ctx.bumps.submitter_record;
+    submitter_record.submission_nonce = nonce.checked_add(1).ok_or(ArithmeticOverflow)?;
+
+    let mut feedback_board = load_feedback_board(&ctx.accounts.feedback_board)?;
+
+    // Validation: Compare-and-swap guard - the board must still hold the CID the client read
+    if let Some(expected_current_cid) = expected_current_cid {
+        if feedback_board.ipfs_cid != expected_current_cid {
+            return Err(CidConflict.into());
+        }
     }
 
-    // Validation: Basic IPFS CID format check (should start with Qm for base58 or b for base32)
-    if !new_ipfs_cid.starts_with("Qm") && !new_ipfs_cid.starts_with("b") {
-        return Err(InvalidIpfsCid.into());
+    let old_ipfs_cid = apply_feedback_submission(
+        &mut feedback_board,
+        feedback_giver,
+        ctx.accounts.gate_token_account.as_deref(),
+        ctx.accounts.nft_token_account.as_deref(),
+        ctx.accounts.nft_metadata.as_ref().map(|metadata| metadata.as_ref()),
+        &ctx.accounts.platform_config,
+        new_ipfs_cid,
+        content_bytes,
+        1,
+    )?;
+
+    // Record the submitter's hash of the new payload so clients can verify what IPFS serves
+    feedback_board.content_hash = content_hash;
+
+    // Partner wallets on the fee exemption list submit for free. The list is capped at
+    // MAX_FEE_EXEMPT_WALLETS, so the lookup stays cheap for everyone else
+    let fee_exempt = ctx.accounts.platform_config.fee_exempt.contains(&feedback_giver);
+
+    if !fee_exempt {
+        // Platform fee for feedback submission, configured by the platform admin
+        let platform_fee = ctx.accounts.platform_config.submit_fee;
+
+        // Transfer platform fee via CPI
+        let ix = transfer(
+            &ctx.accounts.feedback_giver.key(),
+            &ctx.accounts.treasury.key(),
+            platform_fee,
+        );
+
+        invoke(
+            &ix,
+            &[
+                ctx.accounts.feedback_giver.to_account_info(),
+                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+
+        ctx.accounts.platform_config.record_fee(platform_fee)?;
+        feedback_board.record_fee(platform_fee)?;
     }
 
-    let feedback_board = &mut ctx.accounts.feedback_board;
+    store_feedback_board(&ctx.accounts.feedback_board, &feedback_board)?;
+
+    emit_feedback_submitted(&feedback_board, feedback_giver, old_ipfs_cid, fee_exempt);
+
+    Ok(())
+}
+
+// Validates a submission of `item_count` feedback items and applies it to the board,
+// returning the CID it replaced. Shared by every submission path; the caller charges the fee.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_feedback_submission(
+    feedback_board: &mut FeedbackBoard,
+    feedback_giver: Pubkey,
+    gate_token_account: Option<&TokenAccount>,
+    nft_token_account: Option<&TokenAccount>,
+    nft_metadata: Option<&AccountInfo>,
+    platform_config: &PlatformConfig,
+    new_ipfs_cid: String,
+    content_bytes: Option<u32>,
+    item_count: u32,
+) -> Result<String> {
+    // Validation: Check IPFS CID is non-empty with a valid multibase prefix, length and alphabet
+    validate_ipfs_cid(&new_ipfs_cid, feedback_board.storage_kind, platform_config)?;
 
-    // Validation: Check if the feedback giver is not the board creator
-    if feedback_board.creator == ctx.accounts.feedback_giver.key() {
+    let item_total = check_feedback_submission(
+        feedback_board,
+        feedback_giver,
+        gate_token_account,
+        nft_token_account,
+        nft_metadata,
+        content_bytes,
+        item_count,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // Update IPFS CID with new feedback data; the previous payload's hash no longer applies
+    let old_ipfs_cid = std::mem::replace(&mut feedback_board.ipfs_cid, new_ipfs_cid);
+    feedback_board.content_hash = None;
+    feedback_board.updated_at = now;
+    feedback_board.last_submission_ts = now;
+    feedback_board.feedback_seq = feedback_board
+        .feedback_seq
+        .checked_add(u64::from(item_count))
+        .ok_or(ArithmeticOverflow)?;
+    feedback_board.total_interactions = feedback_board
+        .total_interactions
+        .checked_add(u64::from(item_count))
+        .ok_or(ArithmeticOverflow)?;
+    feedback_board.item_count = item_total;
+
+    Ok(old_ipfs_cid)
+}
+
+// Checks whether `feedback_giver` may submit `item_count` items to the board right now,
+// returning the board's item count after the submission. Shared by apply_feedback_submission
+// and append_feedback, which differ only in where the CID is recorded.
+pub(crate) fn check_feedback_submission(
+    feedback_board: &FeedbackBoard,
+    feedback_giver: Pubkey,
+    gate_token_account: Option<&TokenAccount>,
+    nft_token_account: Option<&TokenAccount>,
+    nft_metadata: Option<&AccountInfo>,
+    content_bytes: Option<u32>,
+    item_count: u32,
+) -> Result<u32> {
+    // Validation: Check the reported payload size meets the board's minimum. Submissions that
+    // don't report a size count as 0 bytes, so they're rejected when a minimum is set
+    if content_bytes.unwrap_or(0) < feedback_board.min_content_bytes {
+        return Err(ContentTooShort.into());
+    }
+
+    // Validation: Check if the feedback giver is not the board owner
+    if feedback_board.owner == feedback_giver {
         return Err(CreatorCannotSubmit.into());
     }
 
-    // Fixed platform fee for feedback submission: 1 lamport
-    const PLATFORM_FEE_SUBMIT_FEEDBACK: u64 = 1;
+    // Validation: Check the feedback giver isn't on the board's blocklist
+    if feedback_board.blocked.contains(&feedback_giver) {
+        return Err(WalletBlocked.into());
+    }
 
-    // Transfer platform fee via CPI
-    let ix = transfer(
-        &ctx.accounts.feedback_giver.key(),
-        &ctx.accounts.platform_wallet.key(),
-        PLATFORM_FEE_SUBMIT_FEEDBACK,
-    );
+    // Validation: Check private boards only accept allowlisted wallets
+    if feedback_board.allowlist_only && !feedback_board.allowed.contains(&feedback_giver) {
+        return Err(NotAllowlisted.into());
+    }
 
-    invoke(
-        &ix,
-        &[
-            ctx.accounts.feedback_giver.to_account_info(),
-            ctx.accounts.platform_wallet.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-    )?;
+    // Validation: Check opt-in token and NFT collection gates
+    check_token_gate(feedback_board, feedback_giver, gate_token_account)?;
+    check_collection_gate(feedback_board, feedback_giver, nft_token_account, nft_metadata)?;
+
+    // Validation: Check the board's CID hasn't been frozen
+    if feedback_board.is_frozen {
+        return Err(BoardFrozen.into());
+    }
 
-    // Update IPFS CID with new feedback data
-    feedback_board.ipfs_cid = new_ipfs_cid;
+    // Validation: Check the owner hasn't paused the board
+    if feedback_board.is_paused {
+        return Err(BoardPaused.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validation: Check the board hasn't passed its expiry
+    if feedback_board.expires_at.is_some_and(|expires_at| now > expires_at) {
+        return Err(BoardExpired.into());
+    }
+
+    // Validation: Check the board's minimum interval between submissions has elapsed
+    if now - feedback_board.last_submission_ts < feedback_board.min_submission_interval {
+        return Err(SubmissionTooSoon.into());
+    }
+
+    // Validation: Check the submission fits under the board's item cap
+    let item_total = feedback_board
+        .item_count
+        .checked_add(item_count)
+        .ok_or(ArithmeticOverflow)?;
 
+    if feedback_board.max_items != 0 && item_total > feedback_board.max_items {
+        return Err(BoardFull.into());
+    }
+
+    Ok(item_total)
+}
+
+pub(crate) fn emit_feedback_submitted(
+    feedback_board: &FeedbackBoard,
+    feedback_giver: Pubkey,
+    old_ipfs_cid: String,
+    fee_exempt: bool,
+) {
     msg!(
-        "Feedback submitted. Updated IPFS CID: {}",
+        "Feedback submitted. Previous IPFS CID: {}. Updated IPFS CID: {}",
+        old_ipfs_cid,
         feedback_board.ipfs_cid
     );
 
     // Emit event
     emit!(FeedbackSubmitted {
         board_id: feedback_board.board_id.clone(),
+        old_ipfs_cid,
         new_ipfs_cid: feedback_board.ipfs_cid.clone(),
-        feedback_giver: ctx.accounts.feedback_giver.key(),
+        feedback_giver,
+        feedback_seq: feedback_board.feedback_seq,
+        content_hash: feedback_board.content_hash,
+        fee_exempt,
+        timestamp: feedback_board.updated_at,
     });
-
-    Ok(())
 }
 
 #[derive(Accounts)]
 pub struct SubmitFeedback<'info> {
+    /// CHECK: Loaded with load_feedback_board, which checks the owner, discriminator, PDA
+    /// address and schema version and reports a missing board as FeedbackBoardNotFound
+    #[account(mut)]
+    pub feedback_board: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub feedback_giver: Signer<'info>,
+
+    // Created on the giver's first submission, with the giver paying rent
     #[account(
-        mut,
-        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        init_if_needed,
+        payer = feedback_giver,
+        space = SubmitterRecord::SPACE,
+        seeds = [b"submitter_record", feedback_giver.key().as_ref()],
         bump
     )]
-    pub feedback_board: Account<'info, FeedbackBoard>,
+    pub submitter_record: Account<'info, SubmitterRecord>,
 
-    #[account(mut)]
-    pub feedback_giver: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.is_initialized @ ConfigNotInitialized,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Required only when the board is token-gated
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    // Required only when the board is NFT-collection-gated
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified as the Metaplex metadata PDA of nft_token_account's mint before use
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
 
-    /// CHECK: This is safe as we're only transferring to this hardcoded address
     #[account(
         mut,
-        address = PLATFORM_FEE_WALLET.parse::<Pubkey>().unwrap()
+        seeds = [b"treasury"],
+        bump
     )]
-    pub platform_wallet: UncheckedAccount<'info>,
+    pub treasury: Account<'info, Treasury>,
 
     pub system_program: Program<'info, System>,
 }
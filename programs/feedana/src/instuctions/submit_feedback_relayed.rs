@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+use anchor_spl::token::TokenAccount;
+
+use crate::types::{PlatformConfig, Treasury};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::RelayedFeedbackSubmitted;
+use crate::instuctions::submit_feedback::apply_feedback_submission;
+use crate::utils::{load_feedback_board, store_feedback_board, validate_wallet};
+
+// The relayer signs and pays on behalf of `feedback_giver`, which never touches the
+// transaction. The program can't tell whether the giver approved the submission, so only
+// the board's allowed relayer, trusted by the owner to verify that off-chain, may call this.
+// Every per-giver check still runs against `feedback_giver`.
+pub fn submit_feedback_relayed(
+    ctx: Context<SubmitFeedbackRelayed>,
+    new_ipfs_cid: String,
+    feedback_giver: Pubkey,
+    content_bytes: Option<u32>,
+) -> Result<()> {
+    // Validation: Check the giver is a real wallet; it is an argument, so nothing else does
+    validate_wallet(&feedback_giver)?;
+
+    let relayer = ctx.accounts.relayer.key();
+    let mut feedback_board = load_feedback_board(&ctx.accounts.feedback_board)?;
+
+    // Validation: Check the board has opted in to relaying and the relayer is the allowed one.
+    // Without this any signer could submit as any wallet, e.g. an allowlisted one.
+    if feedback_board.allowed_relayer != Some(relayer) {
+        return Err(UnauthorizedRelayer.into());
+    }
+
+    let old_ipfs_cid = apply_feedback_submission(
+        &mut feedback_board,
+        feedback_giver,
+        ctx.accounts.gate_token_account.as_deref(),
+        ctx.accounts.nft_token_account.as_deref(),
+        ctx.accounts.nft_metadata.as_ref().map(|metadata| metadata.as_ref()),
+        &ctx.accounts.platform_config,
+        new_ipfs_cid,
+        content_bytes,
+        1,
+    )?;
+
+    // Platform fee for feedback submission, paid by the relayer
+    let platform_fee = ctx.accounts.platform_config.submit_fee;
+
+    // Transfer platform fee via CPI
+    let ix = transfer(&relayer, &ctx.accounts.treasury.key(), platform_fee);
+
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.relayer.to_account_info(),
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    ctx.accounts.platform_config.record_fee(platform_fee)?;
+    feedback_board.record_fee(platform_fee)?;
+
+    store_feedback_board(&ctx.accounts.feedback_board, &feedback_board)?;
+
+    msg!(
+        "Feedback relayed by {} for {}. Previous IPFS CID: {}. Updated IPFS CID: {}",
+        relayer,
+        feedback_giver,
+        old_ipfs_cid,
+        feedback_board.ipfs_cid
+    );
+
+    // Emit event
+    emit!(RelayedFeedbackSubmitted {
+        board_id: feedback_board.board_id,
+        old_ipfs_cid,
+        new_ipfs_cid: feedback_board.ipfs_cid,
+        feedback_giver,
+        relayer,
+        feedback_seq: feedback_board.feedback_seq,
+        timestamp: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitFeedbackRelayed<'info> {
+    /// CHECK: Loaded with load_feedback_board, which checks the owner, discriminator, PDA
+    /// address and schema version and reports a missing board as FeedbackBoardNotFound
+    #[account(mut)]
+    pub feedback_board: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.is_initialized @ ConfigNotInitialized,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Required only when the board is token-gated; must belong to the feedback giver
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    // Required only when the board is NFT-collection-gated; must belong to the feedback giver
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified as the Metaplex metadata PDA of nft_token_account's mint before use
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
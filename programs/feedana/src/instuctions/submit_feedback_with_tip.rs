@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+use anchor_spl::token::TokenAccount;
+
+use crate::types::{PlatformConfig, Treasury};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeedbackTipped;
+use crate::instuctions::submit_feedback::apply_feedback_submission;
+use crate::utils::{load_feedback_board, store_feedback_board};
+
+// submit_feedback plus a tip sent straight to the board owner. The platform fee is
+// charged as usual, and tip_fee_bps of the tip goes to the treasury; submit_feedback
+// remains the path for feedback without a tip.
+pub fn submit_feedback_with_tip(
+    ctx: Context<SubmitFeedbackWithTip>,
+    new_ipfs_cid: String,
+    tip_amount: u64,
+    content_bytes: Option<u32>,
+) -> Result<()> {
+    // Validation: Check the tip is non-zero
+    if tip_amount == 0 {
+        return Err(InvalidTipAmount.into());
+    }
+
+    let feedback_giver = ctx.accounts.feedback_giver.key();
+    let mut feedback_board = load_feedback_board(&ctx.accounts.feedback_board)?;
+
+    // Validation: Check the tip goes to this board's current owner, who may no longer be the
+    // creator after a transfer_ownership
+    if ctx.accounts.owner.key() != feedback_board.owner {
+        return Err(InvalidTipRecipient.into());
+    }
+
+    let old_ipfs_cid = apply_feedback_submission(
+        &mut feedback_board,
+        feedback_giver,
+        ctx.accounts.gate_token_account.as_deref(),
+        ctx.accounts.nft_token_account.as_deref(),
+        ctx.accounts.nft_metadata.as_ref().map(|metadata| metadata.as_ref()),
+        &ctx.accounts.platform_config,
+        new_ipfs_cid,
+        content_bytes,
+        1,
+    )?;
+
+    // Platform fee for feedback submission, configured by the platform admin
+    let platform_fee = ctx.accounts.platform_config.submit_fee;
+
+    // Transfer platform fee via CPI
+    let ix = transfer(&feedback_giver, &ctx.accounts.treasury.key(), platform_fee);
+
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.feedback_giver.to_account_info(),
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    ctx.accounts.platform_config.record_fee(platform_fee)?;
+    feedback_board.record_fee(platform_fee)?;
+
+    // Split the tip; the platform's share rounds down, so a tiny tip can go entirely to the owner
+    let platform_tip = (u128::from(tip_amount)
+        * u128::from(ctx.accounts.platform_config.tip_fee_bps)
+        / u128::from(PlatformConfig::MAX_TIP_FEE_BPS)) as u64;
+    let owner_tip = tip_amount - platform_tip;
+
+    if platform_tip > 0 {
+        // Transfer the platform's share of the tip via CPI
+        let ix = transfer(&feedback_giver, &ctx.accounts.treasury.key(), platform_tip);
+
+        invoke(
+            &ix,
+            &[
+                ctx.accounts.feedback_giver.to_account_info(),
+                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+
+        ctx.accounts.platform_config.record_fee(platform_tip)?;
+    }
+
+    // Transfer the rest of the tip to the owner via CPI
+    let ix = transfer(&feedback_giver, &ctx.accounts.owner.key(), owner_tip);
+
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.feedback_giver.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    store_feedback_board(&ctx.accounts.feedback_board, &feedback_board)?;
+
+    msg!(
+        "Feedback submitted with a {} lamport tip. Previous IPFS CID: {}. Updated IPFS CID: {}",
+        tip_amount,
+        old_ipfs_cid,
+        feedback_board.ipfs_cid
+    );
+
+    // Emit event
+    emit!(FeedbackTipped {
+        board_id: feedback_board.board_id,
+        old_ipfs_cid,
+        new_ipfs_cid: feedback_board.ipfs_cid,
+        feedback_giver,
+        creator: feedback_board.creator,
+        owner: feedback_board.owner,
+        tip_amount,
+        platform_tip,
+        owner_tip,
+        feedback_seq: feedback_board.feedback_seq,
+        timestamp: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitFeedbackWithTip<'info> {
+    /// CHECK: Loaded with load_feedback_board, which checks the owner, discriminator, PDA
+    /// address and schema version and reports a missing board as FeedbackBoardNotFound
+    #[account(mut)]
+    pub feedback_board: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub feedback_giver: Signer<'info>,
+
+    /// CHECK: Checked against the loaded board's owner before the tip is sent
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.is_initialized @ ConfigNotInitialized,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Required only when the board is token-gated
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    // Required only when the board is NFT-collection-gated
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified as the Metaplex metadata PDA of nft_token_account's mint before use
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::instuctions::submit_feedback::{apply_feedback_submission, emit_feedback_submitted};
+use crate::utils::{load_feedback_board, store_feedback_board};
+
+pub fn submit_feedback_with_token(
+    ctx: Context<SubmitFeedbackWithToken>,
+    new_ipfs_cid: String,
+    content_bytes: Option<u32>,
+) -> Result<()> {
+    let feedback_giver = ctx.accounts.feedback_giver.key();
+    let mut feedback_board = load_feedback_board(&ctx.accounts.feedback_board)?;
+
+    let old_ipfs_cid = apply_feedback_submission(
+        &mut feedback_board,
+        feedback_giver,
+        ctx.accounts.gate_token_account.as_deref(),
+        ctx.accounts.nft_token_account.as_deref(),
+        ctx.accounts.nft_metadata.as_ref().map(|metadata| metadata.as_ref()),
+        &ctx.accounts.platform_config,
+        new_ipfs_cid,
+        content_bytes,
+        1,
+    )?;
+
+    // Platform fee in the configured SPL token
+    let token_fee = ctx.accounts.platform_config.token_fee_amount;
+
+    // Transfer platform fee via token program CPI
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.feedback_giver_token_account.to_account_info(),
+                to: ctx.accounts.platform_token_account.to_account_info(),
+                authority: ctx.accounts.feedback_giver.to_account_info(),
+            },
+        ),
+        token_fee,
+    )?;
+
+    store_feedback_board(&ctx.accounts.feedback_board, &feedback_board)?;
+
+    emit_feedback_submitted(&feedback_board, feedback_giver, old_ipfs_cid, false);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitFeedbackWithToken<'info> {
+    /// CHECK: Loaded with load_feedback_board, which checks the owner, discriminator, PDA
+    /// address and schema version and reports a missing board as FeedbackBoardNotFound
+    #[account(mut)]
+    pub feedback_board: UncheckedAccount<'info>,
+
+    pub feedback_giver: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.is_initialized @ ConfigNotInitialized,
+        constraint = !platform_config.program_paused @ ProgramPaused,
+        constraint = platform_config.fee_mint != Pubkey::default() @ TokenFeeNotConfigured
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Required only when the board is token-gated
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    // Required only when the board is NFT-collection-gated
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified as the Metaplex metadata PDA of nft_token_account's mint before use
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        token::mint = platform_config.fee_mint,
+        token::authority = feedback_giver
+    )]
+    pub feedback_giver_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = platform_config.fee_mint,
+        constraint = platform_token_account.owner == platform_config.fee_wallet @ InvalidFeeWallet
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::BoardOwnershipTransferred;
+use crate::utils::{check_cosigner, validate_wallet};
+
+pub fn transfer_board_ownership(
+    ctx: Context<TransferBoardOwnership>,
+    new_owner: Pubkey,
+) -> Result<()> {
+    // Validation: Check the new owner is a real wallet
+    validate_wallet(&new_owner)?;
+
+    // Validation: Check the board's cosigner, if any, also signed
+    check_cosigner(&ctx.accounts.feedback_board, ctx.accounts.cosigner.as_ref())?;
+
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // `creator` stays untouched because it is part of the PDA seeds; only the
+    // authorization field moves to the new owner.
+    let previous_owner = feedback_board.owner;
+    feedback_board.owner = new_owner;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Feedback board {} ownership transferred to {}",
+        feedback_board.board_id,
+        feedback_board.owner
+    );
+
+    // Emit event
+    emit!(BoardOwnershipTransferred {
+        board_id: feedback_board.board_id.clone(),
+        previous_owner,
+        new_owner: feedback_board.owner,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferBoardOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Required only when the board has a cosigner
+    pub cosigner: Option<Signer<'info>>,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::FeesUpdated;
+
+// Upper bound on any single fee so a mistyped value can't lock users out
+pub(crate) const MAX_PLATFORM_FEE: u64 = LAMPORTS_PER_SOL;
+
+pub fn update_fees(ctx: Context<UpdateFees>, create_board_fee: u64, submit_fee: u64) -> Result<()> {
+    // Validation: Check that no individual fee exceeds the cap
+    if create_board_fee > MAX_PLATFORM_FEE || submit_fee > MAX_PLATFORM_FEE {
+        return Err(FeeTooHigh.into());
+    }
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    // Validation: Check neither fee goes up; increases wait out the fee timelock instead
+    if create_board_fee > platform_config.create_board_fee
+        || submit_fee > platform_config.submit_fee
+    {
+        return Err(FeeIncreaseRequiresTimelock.into());
+    }
+
+    platform_config.create_board_fee = create_board_fee;
+    platform_config.submit_fee = submit_fee;
+
+    msg!(
+        "Platform fees updated. Create board: {}, submit feedback: {}",
+        platform_config.create_board_fee,
+        platform_config.submit_fee
+    );
+
+    // Emit event
+    emit!(FeesUpdated {
+        admin: platform_config.admin,
+        create_board_fee: platform_config.create_board_fee,
+        submit_fee: platform_config.submit_fee,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::GatingUpdated;
+
+pub fn update_gating(
+    ctx: Context<UpdateGating>,
+    gate_mint: Option<Pubkey>,
+    gate_min_balance: u64,
+    allowlist_only: bool,
+) -> Result<()> {
+    let feedback_board = &mut ctx.accounts.feedback_board;
+
+    // Replaces the settings chosen at creation; the allowlist itself keeps its entries
+    feedback_board.gate_mint = gate_mint;
+    feedback_board.gate_min_balance = gate_min_balance;
+    feedback_board.allowlist_only = allowlist_only;
+    feedback_board.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Feedback board {} gating updated. Gate mint: {:?}, min balance: {}, allowlist only: {}",
+        feedback_board.board_id,
+        feedback_board.gate_mint,
+        feedback_board.gate_min_balance,
+        feedback_board.allowlist_only
+    );
+
+    // Emit event
+    emit!(GatingUpdated {
+        board_id: feedback_board.board_id.clone(),
+        owner: feedback_board.owner,
+        gate_mint: feedback_board.gate_mint,
+        gate_min_balance: feedback_board.gate_min_balance,
+        allowlist_only: feedback_board.allowlist_only,
+        updated_at: feedback_board.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateGating<'info> {
+    #[account(
+        mut,
+        seeds = [b"feedback_board", feedback_board.creator.as_ref(), feedback_board.board_id.as_bytes()],
+        bump = feedback_board.bump,
+        has_one = owner @ UnauthorizedAccess,
+        constraint = feedback_board.schema_version == FeedbackBoard::CURRENT_SCHEMA_VERSION @ UnsupportedSchemaVersion
+    )]
+    pub feedback_board: Account<'info, FeedbackBoard>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = !platform_config.program_paused @ ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::types::PlatformConfig;
+use crate::errors::FeedbackBoardError::*;
+use crate::events::TokenFeeUpdated;
+
+// Upper bound on the token fee in the mint's base units, 1,000 tokens of a 9-decimal mint
+pub(crate) const MAX_TOKEN_FEE: u64 = 1_000_000_000_000;
+
+pub fn update_token_fee(
+    ctx: Context<UpdateTokenFee>,
+    fee_mint: Pubkey,
+    token_fee_amount: u64,
+) -> Result<()> {
+    // Validation: Check the token fee doesn't exceed the cap
+    if token_fee_amount > MAX_TOKEN_FEE {
+        return Err(FeeTooHigh.into());
+    }
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    // Validation: Check the amount doesn't go up; increases wait out the fee timelock instead
+    if token_fee_amount > platform_config.token_fee_amount {
+        return Err(FeeIncreaseRequiresTimelock.into());
+    }
+
+    // Validation: Check the mint is unchanged or being disabled. The same amount of a mint
+    // worth more per unit is a fee increase, so switching mints waits out the timelock too
+    if fee_mint != platform_config.fee_mint && fee_mint != Pubkey::default() {
+        return Err(FeeIncreaseRequiresTimelock.into());
+    }
+
+    // Setting fee_mint to the default pubkey disables the token-fee instructions
+    platform_config.fee_mint = fee_mint;
+    platform_config.token_fee_amount = token_fee_amount;
+
+    msg!(
+        "Token fee updated. Mint: {}, amount: {}",
+        platform_config.fee_mint,
+        platform_config.token_fee_amount
+    );
+
+    // Emit event
+    emit!(TokenFeeUpdated {
+        admin: platform_config.admin,
+        fee_mint: platform_config.fee_mint,
+        token_fee_amount: platform_config.token_fee_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateTokenFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
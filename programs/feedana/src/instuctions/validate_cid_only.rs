@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{PlatformConfig, StorageKind};
+use crate::events::CidValidationResult;
+use crate::utils::validate_ipfs_cid;
+
+// Read-only: runs the shared CID validator and reports the outcome as an event instead of
+// failing, so clients can pre-check a CID with simulateTransaction
+pub fn validate_cid_only(
+    ctx: Context<ValidateCidOnly>,
+    cid: String,
+    storage_kind: StorageKind,
+) -> Result<()> {
+    let (valid, reason, error_code) = match validate_ipfs_cid(&cid, storage_kind, &ctx.accounts.platform_config) {
+        Ok(()) => (true, None, None),
+        Err(Error::AnchorError(error)) => {
+            (false, Some(error.error_name), Some(error.error_code_number))
+        }
+        Err(error) => return Err(error),
+    };
+
+    msg!("CID {} is {}", cid, if valid { "valid" } else { "invalid" });
+
+    // Emit event
+    emit!(CidValidationResult {
+        cid,
+        storage_kind,
+        valid,
+        reason,
+        error_code,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ValidateCidOnly<'info> {
+    pub invoker: Signer<'info>,
+
+    // Supplies the CID length range; read-only, so validation works while paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{PlatformConfig, Treasury};
+use crate::errors::FeedbackBoardError::*;
+use crate::events::TreasuryWithdrawal;
+
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let treasury = ctx.accounts.treasury.to_account_info();
+
+    // Validation: Check the treasury stays rent-exempt after the withdrawal
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(treasury.data_len());
+    let available = treasury.lamports().saturating_sub(rent_exempt_minimum);
+
+    if amount > available {
+        return Err(InsufficientTreasuryBalance.into());
+    }
+
+    // The treasury is program-owned, so lamports are moved directly rather than via CPI
+    treasury.sub_lamports(amount)?;
+    ctx.accounts.destination.add_lamports(amount)?;
+
+    msg!(
+        "Withdrew {} lamports from the treasury to {}",
+        amount,
+        ctx.accounts.destination.key()
+    );
+
+    // Emit event
+    emit!(TreasuryWithdrawal {
+        admin: ctx.accounts.admin.key(),
+        recipient: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        has_one = admin @ UnauthorizedAccess
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Any account can receive lamports; the admin chooses the recipient
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
@@ -1,3 +1,6 @@
+// Anchor 0.31 `#[program]` codegen still calls the deprecated `AccountInfo::realloc`
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
 declare_id!("3TwZoBQB7g8roimCHwUW7JTEHjGeZwvjcdQM5AeddqMY");
@@ -7,22 +10,410 @@ pub mod errors;
 pub mod events;
 pub mod instuctions;
 pub mod types;
+pub mod utils;
 
 use instuctions::*;
+use types::{CostOperation, CreateBoardOptions, StorageKind};
 
 #[program]
 pub mod feedana {
     use super::*;
 
+    pub fn initialize_platform_config(
+        ctx: Context<InitializePlatformConfig>,
+        fee_wallet: Pubkey,
+    ) -> Result<()> {
+        instuctions::initialize_platform_config::initialize_platform_config(ctx, fee_wallet)
+    }
+
     pub fn create_feedback_board(
         ctx: Context<CreateFeedbackBoard>,
         board_id: String,
         ipfs_cid: String,
+        title: String,
+        options: CreateBoardOptions,
+    ) -> Result<()> {
+        instuctions::create_board::create_feedback_board(ctx, board_id, ipfs_cid, title, options)
+    }
+
+    pub fn create_feedback_board_idempotent(
+        ctx: Context<CreateFeedbackBoard>,
+        board_id: String,
+        ipfs_cid: String,
+        title: String,
+        options: CreateBoardOptions,
+    ) -> Result<()> {
+        instuctions::create_board_idempotent::create_feedback_board_idempotent(
+            ctx, board_id, ipfs_cid, title, options,
+        )
+    }
+
+    pub fn create_feedback_board_with_token(
+        ctx: Context<CreateFeedbackBoardWithToken>,
+        board_id: String,
+        ipfs_cid: String,
+        title: String,
+        options: CreateBoardOptions,
+    ) -> Result<()> {
+        instuctions::create_board_with_token::create_feedback_board_with_token(
+            ctx, board_id, ipfs_cid, title, options,
+        )
+    }
+
+    pub fn submit_feedback(
+        ctx: Context<SubmitFeedback>,
+        new_ipfs_cid: String,
+        expected_current_cid: Option<String>,
+        content_hash: Option<[u8; 32]>,
+        content_bytes: Option<u32>,
+        nonce: u64,
+    ) -> Result<()> {
+        instuctions::submit_feedback::submit_feedback(
+            ctx,
+            new_ipfs_cid,
+            expected_current_cid,
+            content_hash,
+            content_bytes,
+            nonce,
+        )
+    }
+
+    pub fn append_feedback(
+        ctx: Context<AppendFeedback>,
+        ipfs_cid: String,
+        content_bytes: Option<u32>,
+        seq: u64,
+    ) -> Result<()> {
+        instuctions::append_feedback::append_feedback(ctx, ipfs_cid, content_bytes, seq)
+    }
+
+    pub fn submit_feedback_with_tip(
+        ctx: Context<SubmitFeedbackWithTip>,
+        new_ipfs_cid: String,
+        tip_amount: u64,
+        content_bytes: Option<u32>,
+    ) -> Result<()> {
+        instuctions::submit_feedback_with_tip::submit_feedback_with_tip(
+            ctx,
+            new_ipfs_cid,
+            tip_amount,
+            content_bytes,
+        )
+    }
+
+    pub fn submit_feedback_with_token(
+        ctx: Context<SubmitFeedbackWithToken>,
+        new_ipfs_cid: String,
+        content_bytes: Option<u32>,
+    ) -> Result<()> {
+        instuctions::submit_feedback_with_token::submit_feedback_with_token(
+            ctx,
+            new_ipfs_cid,
+            content_bytes,
+        )
+    }
+
+    pub fn batch_submit_feedback(
+        ctx: Context<BatchSubmitFeedback>,
+        new_ipfs_cid: String,
+        item_count: u32,
+        content_bytes: Option<u32>,
+    ) -> Result<()> {
+        instuctions::batch_submit_feedback::batch_submit_feedback(
+            ctx,
+            new_ipfs_cid,
+            item_count,
+            content_bytes,
+        )
+    }
+
+    pub fn submit_feedback_relayed(
+        ctx: Context<SubmitFeedbackRelayed>,
+        new_ipfs_cid: String,
+        feedback_giver: Pubkey,
+        content_bytes: Option<u32>,
+    ) -> Result<()> {
+        instuctions::submit_feedback_relayed::submit_feedback_relayed(
+            ctx,
+            new_ipfs_cid,
+            feedback_giver,
+            content_bytes,
+        )
+    }
+
+    pub fn report_feedback(ctx: Context<ReportFeedback>, item_ref: String) -> Result<()> {
+        instuctions::report_feedback::report_feedback(ctx, item_ref)
+    }
+
+    pub fn creator_reply(ctx: Context<CreatorReply>, new_ipfs_cid: String) -> Result<()> {
+        instuctions::creator_reply::creator_reply(ctx, new_ipfs_cid)
+    }
+
+    pub fn delete_feedback_item(
+        ctx: Context<DeleteFeedbackItem>,
+        new_ipfs_cid: String,
+        item_ref: String,
+    ) -> Result<()> {
+        instuctions::delete_feedback_item::delete_feedback_item(ctx, new_ipfs_cid, item_ref)
+    }
+
+    pub fn refund_contributor(ctx: Context<RefundContributor>, amount: u64) -> Result<()> {
+        instuctions::refund_contributor::refund_contributor(ctx, amount)
+    }
+
+    pub fn edit_board_metadata(
+        ctx: Context<EditBoardMetadata>,
+        new_ipfs_cid: String,
+    ) -> Result<()> {
+        instuctions::edit_board_metadata::edit_board_metadata(ctx, new_ipfs_cid)
+    }
+
+    pub fn correct_initial_cid(
+        ctx: Context<CorrectInitialCid>,
+        new_ipfs_cid: String,
+    ) -> Result<()> {
+        instuctions::correct_initial_cid::correct_initial_cid(ctx, new_ipfs_cid)
+    }
+
+    pub fn freeze_board_cid(ctx: Context<FreezeBoardCid>) -> Result<()> {
+        instuctions::freeze_board_cid::freeze_board_cid(ctx)
+    }
+
+    pub fn get_board_state(ctx: Context<GetBoardState>) -> Result<()> {
+        instuctions::get_board_state::get_board_state(ctx)
+    }
+
+    pub fn get_board_stats(ctx: Context<GetBoardStats>) -> Result<()> {
+        instuctions::get_board_stats::get_board_stats(ctx)
+    }
+
+    pub fn resolve_board(
+        ctx: Context<ResolveBoard>,
+        creator: Pubkey,
+        board_id: String,
+    ) -> Result<()> {
+        instuctions::resolve_board::resolve_board(ctx, creator, board_id)
+    }
+
+    pub fn get_entry_range(ctx: Context<GetEntryRange>, start: u64, count: u8) -> Result<()> {
+        instuctions::get_entry_range::get_entry_range(ctx, start, count)
+    }
+
+    pub fn get_creator_boards(ctx: Context<GetCreatorBoards>) -> Result<()> {
+        instuctions::get_creator_boards::get_creator_boards(ctx)
+    }
+
+    pub fn validate_cid_only(
+        ctx: Context<ValidateCidOnly>,
+        cid: String,
+        storage_kind: StorageKind,
+    ) -> Result<()> {
+        instuctions::validate_cid_only::validate_cid_only(ctx, cid, storage_kind)
+    }
+
+    pub fn migrate_board(ctx: Context<MigrateBoard>) -> Result<()> {
+        instuctions::migrate_board::migrate_board(ctx)
+    }
+
+    pub fn pause_board(ctx: Context<PauseBoard>) -> Result<()> {
+        instuctions::pause_board::pause_board(ctx)
+    }
+
+    pub fn resume_board(ctx: Context<ResumeBoard>) -> Result<()> {
+        instuctions::resume_board::resume_board(ctx)
+    }
+
+    pub fn set_listed(ctx: Context<SetListed>, is_listed: bool) -> Result<()> {
+        instuctions::set_listed::set_listed(ctx, is_listed)
+    }
+
+    pub fn set_board_expiry(ctx: Context<SetBoardExpiry>, expires_at: Option<i64>) -> Result<()> {
+        instuctions::set_board_expiry::set_board_expiry(ctx, expires_at)
+    }
+
+    pub fn update_gating(
+        ctx: Context<UpdateGating>,
+        gate_mint: Option<Pubkey>,
+        gate_min_balance: u64,
+        allowlist_only: bool,
+    ) -> Result<()> {
+        instuctions::update_gating::update_gating(ctx, gate_mint, gate_min_balance, allowlist_only)
+    }
+
+    pub fn block_wallet(ctx: Context<BlockWallet>, wallet: Pubkey) -> Result<()> {
+        instuctions::block_wallet::block_wallet(ctx, wallet)
+    }
+
+    pub fn unblock_wallet(ctx: Context<UnblockWallet>, wallet: Pubkey) -> Result<()> {
+        instuctions::unblock_wallet::unblock_wallet(ctx, wallet)
+    }
+
+    pub fn add_co_creator(ctx: Context<AddCoCreator>, co_creator: Pubkey) -> Result<()> {
+        instuctions::add_co_creator::add_co_creator(ctx, co_creator)
+    }
+
+    pub fn remove_co_creator(ctx: Context<RemoveCoCreator>, co_creator: Pubkey) -> Result<()> {
+        instuctions::remove_co_creator::remove_co_creator(ctx, co_creator)
+    }
+
+    pub fn allow_wallet(ctx: Context<AllowWallet>, wallet: Pubkey) -> Result<()> {
+        instuctions::allow_wallet::allow_wallet(ctx, wallet)
+    }
+
+    pub fn disallow_wallet(ctx: Context<DisallowWallet>, wallet: Pubkey) -> Result<()> {
+        instuctions::disallow_wallet::disallow_wallet(ctx, wallet)
+    }
+
+    pub fn deposit_bounty(ctx: Context<DepositBounty>, amount: u64) -> Result<()> {
+        instuctions::deposit_bounty::deposit_bounty(ctx, amount)
+    }
+
+    pub fn claim_bounty(ctx: Context<ClaimBounty>, amount: u64) -> Result<()> {
+        instuctions::claim_bounty::claim_bounty(ctx, amount)
+    }
+
+    pub fn set_max_items(ctx: Context<SetMaxItems>, max_items: u32) -> Result<()> {
+        instuctions::set_max_items::set_max_items(ctx, max_items)
+    }
+
+    pub fn set_allowed_relayer(
+        ctx: Context<SetAllowedRelayer>,
+        allowed_relayer: Option<Pubkey>,
+    ) -> Result<()> {
+        instuctions::set_allowed_relayer::set_allowed_relayer(ctx, allowed_relayer)
+    }
+
+    pub fn set_cosigner(ctx: Context<SetCosigner>, cosigner: Option<Pubkey>) -> Result<()> {
+        instuctions::set_cosigner::set_cosigner(ctx, cosigner)
+    }
+
+    pub fn set_board_title(ctx: Context<SetBoardTitle>, title: String) -> Result<()> {
+        instuctions::set_board_title::set_board_title(ctx, title)
+    }
+
+    pub fn set_tags(ctx: Context<SetTags>, tags: Vec<String>) -> Result<()> {
+        instuctions::set_tags::set_tags(ctx, tags)
+    }
+
+    pub fn add_fee_exempt_wallet(ctx: Context<AddFeeExemptWallet>, wallet: Pubkey) -> Result<()> {
+        instuctions::add_fee_exempt_wallet::add_fee_exempt_wallet(ctx, wallet)
+    }
+
+    pub fn remove_fee_exempt_wallet(
+        ctx: Context<RemoveFeeExemptWallet>,
+        wallet: Pubkey,
+    ) -> Result<()> {
+        instuctions::remove_fee_exempt_wallet::remove_fee_exempt_wallet(ctx, wallet)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+        instuctions::propose_admin::propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instuctions::accept_admin::accept_admin(ctx)
+    }
+
+    pub fn set_cid_length_range(
+        ctx: Context<SetCidLengthRange>,
+        cid_min_len: u8,
+        cid_max_len: u8,
+    ) -> Result<()> {
+        instuctions::set_cid_length_range::set_cid_length_range(ctx, cid_min_len, cid_max_len)
+    }
+
+    pub fn set_creator_verified(
+        ctx: Context<SetCreatorVerified>,
+        creator: Pubkey,
+        verified: bool,
+    ) -> Result<()> {
+        instuctions::set_creator_verified::set_creator_verified(ctx, creator, verified)
+    }
+
+    pub fn set_tip_fee_bps(ctx: Context<SetTipFeeBps>, tip_fee_bps: u16) -> Result<()> {
+        instuctions::set_tip_fee_bps::set_tip_fee_bps(ctx, tip_fee_bps)
+    }
+
+    pub fn set_max_board_id_len(ctx: Context<SetMaxBoardIdLen>, max_board_id_len: u8) -> Result<()> {
+        instuctions::set_max_board_id_len::set_max_board_id_len(ctx, max_board_id_len)
+    }
+
+    pub fn set_max_boards_per_creator(
+        ctx: Context<SetMaxBoardsPerCreator>,
+        max_boards_per_creator: u64,
+    ) -> Result<()> {
+        instuctions::set_max_boards_per_creator::set_max_boards_per_creator(
+            ctx,
+            max_boards_per_creator,
+        )
+    }
+
+    pub fn set_program_paused(ctx: Context<SetProgramPaused>, program_paused: bool) -> Result<()> {
+        instuctions::set_program_paused::set_program_paused(ctx, program_paused)
+    }
+
+    pub fn add_banned_word(ctx: Context<AddBannedWord>, word: String) -> Result<()> {
+        instuctions::add_banned_word::add_banned_word(ctx, word)
+    }
+
+    pub fn remove_banned_word(ctx: Context<RemoveBannedWord>, word: String) -> Result<()> {
+        instuctions::remove_banned_word::remove_banned_word(ctx, word)
+    }
+
+    pub fn transfer_board_ownership(
+        ctx: Context<TransferBoardOwnership>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instuctions::transfer_ownership::transfer_board_ownership(ctx, new_owner)
+    }
+
+    pub fn propose_fee_change(
+        ctx: Context<ProposeFeeChange>,
+        create_board_fee: u64,
+        submit_fee: u64,
+        fee_mint: Pubkey,
+        token_fee_amount: u64,
+        tip_fee_bps: u16,
     ) -> Result<()> {
-        instuctions::create_board::create_feedback_board(ctx, board_id, ipfs_cid)
+        instuctions::propose_fee_change::propose_fee_change(
+            ctx,
+            create_board_fee,
+            submit_fee,
+            fee_mint,
+            token_fee_amount,
+            tip_fee_bps,
+        )
+    }
+
+    pub fn apply_fee_change(ctx: Context<ApplyFeeChange>) -> Result<()> {
+        instuctions::apply_fee_change::apply_fee_change(ctx)
+    }
+
+    pub fn update_fees(
+        ctx: Context<UpdateFees>,
+        create_board_fee: u64,
+        submit_fee: u64,
+    ) -> Result<()> {
+        instuctions::update_fees::update_fees(ctx, create_board_fee, submit_fee)
+    }
+
+    pub fn update_token_fee(
+        ctx: Context<UpdateTokenFee>,
+        fee_mint: Pubkey,
+        token_fee_amount: u64,
+    ) -> Result<()> {
+        instuctions::update_token_fee::update_token_fee(ctx, fee_mint, token_fee_amount)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instuctions::withdraw_treasury::withdraw_treasury(ctx, amount)
+    }
+
+    pub fn emit_platform_stats(ctx: Context<EmitPlatformStats>) -> Result<()> {
+        instuctions::emit_platform_stats::emit_platform_stats(ctx)
     }
 
-    pub fn submit_feedback(ctx: Context<SubmitFeedback>, new_ipfs_cid: String) -> Result<()> {
-        instuctions::submit_feedback::submit_feedback(ctx, new_ipfs_cid)
+    pub fn estimate_costs(ctx: Context<EstimateCosts>, operation: CostOperation) -> Result<()> {
+        instuctions::estimate_costs::estimate_costs(ctx, operation)
     }
 }
@@ -1,8 +1,399 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::FeedbackBoardError;
+
 #[account]
 pub struct FeedbackBoard {
+    pub creator: Pubkey,  // 32 bytes - fixed PDA seed, never changes
+    // Lifecycle status at the fixed offset STATUS_OFFSET for getProgramAccounts memcmp
+    // filters; derived from is_paused/is_frozen/is_listed, which remain the source of truth
+    pub status: u8,       // 1 byte - see STATUS_* constants
+    pub ipfs_cid: String, // 4 + up to MAX_CID_LEN bytes (IPFS CIDs are typically ~46 chars)
+    pub board_id: String, // 4 + up to MAX_BOARD_ID_LEN bytes
+    pub title: String,    // 4 + up to MAX_TITLE_LEN bytes - display name, readable without IPFS
+    pub bump: u8,         // 1 byte - canonical bump of the board PDA
+    // Current owner used for authorization checks. Starts as `creator` and can be
+    // handed over with transfer_board_ownership without re-deriving the PDA.
+    pub owner: Pubkey, // 32 bytes
+    pub created_at: i64, // 8 bytes - unix timestamp
+    pub updated_at: i64, // 8 bytes - unix timestamp of the last state change
+    pub index: u64,      // 8 bytes - position in global creation order
+    // Token gating: when set, feedback givers must hold at least
+    // max(gate_min_balance, 1) of this mint to submit
+    pub gate_mint: Option<Pubkey>, // 1 + 32 bytes
+    pub gate_min_balance: u64,     // 8 bytes
+    // NFT gating: when set, feedback givers must hold an NFT from this verified collection
+    pub collection_mint: Option<Pubkey>, // 1 + 32 bytes
+    pub feedback_seq: u64,               // 8 bytes - number of feedback submissions, stable ordering key
+    pub last_submission_ts: i64,         // 8 bytes - unix timestamp of the last submission
+    pub min_submission_interval: i64,    // 8 bytes - seconds required between submissions, 0 = no limit
+    pub expires_at: Option<i64>,         // 1 + 8 bytes - unix timestamp after which feedback is rejected, None = never
+    pub blocked: Vec<Pubkey>,            // 4 + 32 * MAX_BLOCKED_WALLETS bytes - wallets barred from submitting
+    // Private boards: when allowlist_only is set, only wallets in `allowed` may submit.
+    // The blocklist takes precedence - a wallet on both lists is rejected as blocked.
+    pub allowlist_only: bool,            // 1 byte
+    pub allowed: Vec<Pubkey>,            // 4 + 32 * MAX_ALLOWED_WALLETS bytes
+    // Feedback items live in IPFS, so clients must treat item_count as the source of truth
+    // and stop accepting items once max_items is reached
+    pub max_items: u32,                  // 4 bytes - cap on feedback items, 0 = unlimited
+    pub item_count: u32,                 // 4 bytes - feedback items submitted so far
+    // Junk filter: submissions must report a payload of at least this many bytes. The size
+    // is client-reported since content lives off-chain; content_hash lets readers check it.
+    pub min_content_bytes: u32,          // 4 bytes - 0 = no minimum
+    pub is_paused: bool,                 // 1 byte - temporarily rejects submissions, unlike expiry
+    pub is_frozen: bool,                 // 1 byte - ipfs_cid can never change again, irreversible
+    // Discovery hint only: unlisted boards are left out of public listings but still accept
+    // feedback from anyone with the address
+    pub is_listed: bool,                 // 1 byte
+    // Snapshot of CreatorStats.verified when the board was created, so listings can show
+    // the badge without fetching the creator's stats; later changes aren't mirrored
+    pub creator_verified: bool,          // 1 byte
+    // Set by migrate_board, whose back-filled created_at and feedback_seq don't reflect the
+    // board's history; such boards never get the initial CID correction window
+    pub migrated: bool,                  // 1 byte
+    pub tags: Vec<String>,               // 4 + MAX_TAGS * (4 + MAX_TAG_LEN) bytes - discovery tags
+    pub report_count: u64,               // 8 bytes - number of report_feedback calls against the board
+    pub reply_count: u64,                // 8 bytes - number of creator_reply calls by the owner
+    pub deleted_count: u64,              // 8 bytes - feedback items removed with delete_feedback_item
+    pub collected_fees: u64,             // 8 bytes - lamport submit fees paid on this board, less refunds
+    // Activity score for leaderboards: every interaction that counts towards it adds 1.
+    // Only feedback submissions exist today, so it tracks feedback_seq until votes land.
+    pub total_interactions: u64,         // 8 bytes
+    // Relayed submissions: only this wallet may call submit_feedback_relayed, None = disabled
+    pub allowed_relayer: Option<Pubkey>, // 1 + 32 bytes
+    // Two-party control: when set, transfer_board_ownership, freeze_board_cid and
+    // set_cosigner need this wallet's signature alongside the owner's
+    pub cosigner: Option<Pubkey>,        // 1 + 32 bytes
+    // Team boards: co-creators may call edit_board_metadata and creator_reply, but every
+    // other owner-only instruction still requires the owner
+    pub co_creators: Vec<Pubkey>,        // 4 + 32 * MAX_CO_CREATORS bytes
+    // Integrity link: hash of the IPFS payload at ipfs_cid, as claimed by the last submitter.
+    // Any CID change without a new hash clears it.
+    pub content_hash: Option<[u8; 32]>,  // 1 + 32 bytes
+    pub storage_kind: StorageKind,       // 1 byte - what ipfs_cid holds, fixed at creation
+    // Client hint only: the payload at ipfs_cid is ciphertext under encryption_scheme and
+    // must be decrypted before rendering. Nothing is decrypted on-chain.
+    pub encrypted: bool,                 // 1 byte - fixed at creation
+    pub encryption_scheme: Option<String>, // 1 + 4 + MAX_ENCRYPTION_SCHEME_LEN bytes
+    pub schema_version: u8,              // 1 byte - layout version, see CURRENT_SCHEMA_VERSION
+}
+
+impl FeedbackBoard {
+    // discriminator + creator + status + ipfs_cid + board_id + title (Borsh length prefix + max bytes)
+    // + bump + owner + created_at + updated_at + index + gate_mint + gate_min_balance + collection_mint
+    // + feedback_seq + last_submission_ts + min_submission_interval + expires_at + blocked
+    // + allowlist_only + allowed + max_items + item_count + min_content_bytes + is_paused + is_frozen
+    // + is_listed + creator_verified + migrated + tags + report_count + reply_count + deleted_count + collected_fees + total_interactions
+    // + allowed_relayer + cosigner + co_creators + content_hash + storage_kind + encrypted
+    // + encryption_scheme + schema_version
+    pub const SPACE: usize = 8 + 32 + 1 + (4 + Self::MAX_CID_LEN) + (4 + Self::MAX_BOARD_ID_LEN)
+        + (4 + Self::MAX_TITLE_LEN) + 1
+        + 32 + 8 + 8 + 8 + (1 + 32) + 8 + (1 + 32)
+        + 8 + 8 + 8 + (1 + 8) + (4 + 32 * Self::MAX_BLOCKED_WALLETS)
+        + 1 + (4 + 32 * Self::MAX_ALLOWED_WALLETS) + 4 + 4 + 4 + 1 + 1 + 1 + 1 + 1
+        + (4 + Self::MAX_TAGS * (4 + Self::MAX_TAG_LEN))
+        + 8 + 8 + 8 + 8 + 8 + (1 + 32) + (1 + 32) + (4 + 32 * Self::MAX_CO_CREATORS)
+        + (1 + 32) + 1
+        + 1 + (1 + 4 + Self::MAX_ENCRYPTION_SCHEME_LEN) + 1;
+
+    // Layout written by this program. State-mutating instructions reject boards on any other
+    // version with UnsupportedSchemaVersion; older layouts are upgraded with migrate_board.
+    //
+    // Version history:
+    // - 0: original layout (creator, ipfs_cid, board_id) in a 136-byte account, no
+    //      schema_version field - see LegacyFeedbackBoard
+    // - 1: adds status, title, bump, owner, timestamps, index, gating, rate limiting, expiry,
+    //      block/allowlists, item cap, minimum content size, pause, freeze and listing flags,
+    //      creator badge, tags, report, reply and deleted counts, collected fees, total
+    //      interactions, allowed relayer, cosigner, co-creators, content hash, storage kind,
+    //      encryption hint and schema_version
+    pub const CURRENT_SCHEMA_VERSION: u8 = 1;
+
+    // Cap on the blocklist; each entry costs 32 bytes of rent for the board's lifetime
+    pub const MAX_BLOCKED_WALLETS: usize = 10;
+
+    // Cap on the allowlist, with the same per-entry rent cost
+    pub const MAX_ALLOWED_WALLETS: usize = 10;
+
+    // Cap on co-creators; a small team, each entry reserved up front like the lists above
+    pub const MAX_CO_CREATORS: usize = 5;
+
+    // Seconds after created_at during which correct_initial_cid may replace the CID
+    pub const CID_CORRECTION_WINDOW: i64 = 5 * 60;
+
+    // Byte offset of `status`: discriminator + creator
+    pub const STATUS_OFFSET: usize = 8 + 32;
+
+    // Values of `status`. Archived is reserved; no instruction archives boards yet.
+    pub const STATUS_ACTIVE: u8 = 0;
+    pub const STATUS_ARCHIVED: u8 = 1;
+    pub const STATUS_PAUSED: u8 = 2;
+    pub const STATUS_FROZEN: u8 = 3;
+
+    // Set on top of the values above while a board is unlisted, so a memcmp on 0 matches
+    // only listed active boards
+    pub const STATUS_UNLISTED: u8 = 0x80;
+
+    // Adds a lamport submit fee paid on this board to its refundable total
+    pub fn record_fee(&mut self, fee: u64) -> Result<()> {
+        self.collected_fees = self
+            .collected_fees
+            .checked_add(fee)
+            .ok_or(FeedbackBoardError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    // Wallets allowed to post board metadata and replies: the owner and its co-creators
+    pub fn can_post_as_creator(&self, wallet: &Pubkey) -> bool {
+        self.owner == *wallet || self.co_creators.contains(wallet)
+    }
+
+    // Status implied by the flags; frozen wins over paused since it is permanent
+    pub fn current_status(&self) -> u8 {
+        let lifecycle = if self.is_frozen {
+            Self::STATUS_FROZEN
+        } else if self.is_paused {
+            Self::STATUS_PAUSED
+        } else {
+            Self::STATUS_ACTIVE
+        };
+
+        if self.is_listed {
+            lifecycle
+        } else {
+            lifecycle | Self::STATUS_UNLISTED
+        }
+    }
+
+    // Min chars in a board_id, so single-character IDs can't be squatted
+    pub const MIN_BOARD_ID_LEN: usize = 3;
+
+    // Hard cap on board_id bytes: board_id is a PDA seed, and seeds are at most 32 bytes.
+    // PlatformConfig.max_board_id_len can only tighten it.
+    pub const MAX_BOARD_ID_LEN: usize = 32;
+
+    // Hard cap on ipfs_cid bytes, reserved in full on every board.
+    // PlatformConfig.cid_max_len can only tighten it.
+    pub const MAX_CID_LEN: usize = 128;
+
+    // Max bytes in a board title
+    pub const MAX_TITLE_LEN: usize = 64;
+
+    // Max bytes in a report_feedback item reference
+    pub const MAX_ITEM_REF_LEN: usize = 64;
+
+    // Max bytes in an encryption scheme name, e.g. "x25519-aes256gcm"
+    pub const MAX_ENCRYPTION_SCHEME_LEN: usize = 16;
+
+    // Caps on discovery tags: number of tags and bytes per tag
+    pub const MAX_TAGS: usize = 5;
+    pub const MAX_TAG_LEN: usize = 16;
+}
+
+// Original FeedbackBoard layout (creator, ipfs_cid, board_id) in a 136-byte account.
+// Shares FeedbackBoard's discriminator; only read by migrate_board.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyFeedbackBoard {
+    pub creator: Pubkey,
+    pub ipfs_cid: String,
+    pub board_id: String,
+}
+
+// Storage network behind a board's `ipfs_cid`. The field keeps its name for
+// compatibility; on Arweave boards it holds a transaction ID instead of a CID.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageKind {
+    #[default]
+    Ipfs,
+    Arweave,
+}
+
+// Platform fees waiting out the fee timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct PendingFeeChange {
+    pub create_board_fee: u64,
+    pub submit_fee: u64,
+    pub fee_mint: Pubkey,
+    pub token_fee_amount: u64,
+    pub tip_fee_bps: u16,
+    pub effective_at: i64,
+}
+
+// Operation priced by estimate_costs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CostOperation {
+    CreateBoard,
+    SubmitFeedback,
+    BatchSubmitFeedback { item_count: u32 },
+}
+
+// Optional settings chosen by the creator at board creation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateBoardOptions {
+    pub gate_mint: Option<Pubkey>,
+    pub gate_min_balance: u64,
+    pub collection_mint: Option<Pubkey>,
+    pub min_submission_interval: i64,
+    pub expires_at: Option<i64>,
+    pub allowlist_only: bool,
+    pub max_items: u32,
+    pub min_content_bytes: u32,
+    pub tags: Vec<String>,
+    pub storage_kind: StorageKind,
+    pub encrypted: bool,
+    pub encryption_scheme: Option<String>,
+}
+
+#[account]
+pub struct PlatformConfig {
+    pub admin: Pubkey,               // 32 bytes
+    pub fee_wallet: Pubkey,          // 32 bytes
+    pub create_board_fee: u64,       // 8 bytes - lamports
+    pub submit_fee: u64,             // 8 bytes - lamports
+    pub max_boards_per_creator: u64, // 8 bytes
+    pub fee_mint: Pubkey,            // 32 bytes - SPL token accepted for fees, default = token fees disabled
+    pub token_fee_amount: u64,       // 8 bytes - base units charged per token-fee instruction
+    pub program_paused: bool,        // 1 byte - emergency switch, rejects every user write while set
+    pub total_fees: u64,             // 8 bytes - lamport fees paid into the treasury, never reduced by withdrawals
+    pub max_board_id_len: u8,        // 1 byte - between MIN_BOARD_ID_LEN and MAX_BOARD_ID_LEN
+    // Admin rotation: set by propose_admin, becomes admin once it signs accept_admin
+    pub pending_admin: Option<Pubkey>, // 1 + 32 bytes
+    // Partner wallets whose submit_feedback calls skip the submit fee
+    pub fee_exempt: Vec<Pubkey>,       // 4 + 32 * MAX_FEE_EXEMPT_WALLETS bytes
+    // Length range for IPFS CIDs, on top of each encoding's own minimum
+    pub cid_min_len: u8,               // 1 byte
+    pub cid_max_len: u8,               // 1 byte - at most FeedbackBoard::MAX_CID_LEN
+    // Set once by initialize_platform_config; fee-charging instructions require it so a
+    // zeroed config can never be read as zero fees
+    pub is_initialized: bool,          // 1 byte
+    // Fee timelock: set by propose_fee_change, applied by apply_fee_change once effective_at
+    // has passed. Until then every instruction keeps charging the fees above.
+    pub pending_fee_change: Option<PendingFeeChange>, // 1 + 8 + 8 + 32 + 8 + 2 + 8 bytes
+    // Share of each tip sent to the treasury instead of the creator, in basis points
+    pub tip_fee_bps: u16,              // 2 bytes - at most MAX_TIP_FEE_BPS
+}
+
+impl PlatformConfig {
+    // Cap on the fee exemption list; space for every entry is reserved up front
+    pub const MAX_FEE_EXEMPT_WALLETS: usize = 10;
+
+    // Delay between propose_fee_change and the earliest apply_fee_change
+    pub const FEE_CHANGE_DELAY: i64 = 2 * 24 * 60 * 60;
+
+    // Default CID length range set by initialize_platform_config
+    pub const DEFAULT_CID_MIN_LEN: u8 = 32;
+    pub const DEFAULT_CID_MAX_LEN: u8 = 64;
+
+    // tip_fee_bps of 10000 sends the whole tip to the treasury
+    pub const MAX_TIP_FEE_BPS: u16 = 10_000;
+
+    // Adds a lamport fee paid into the treasury to the running total
+    pub fn record_fee(&mut self, fee: u64) -> Result<()> {
+        self.total_fees = self
+            .total_fees
+            .checked_add(fee)
+            .ok_or(FeedbackBoardError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct BoardCounter {
+    pub total: u64, // 8 bytes - number of boards ever created, next index to assign
+}
+
+// Program-owned destination for lamport platform fees, withdrawn by the admin
+#[account]
+pub struct Treasury {}
+
+// Admin-managed board IDs nobody may create, e.g. "admin" or "official"
+#[account]
+pub struct BannedWords {
+    pub words: Vec<String>, // 4 + MAX_BANNED_WORDS * (4 + MAX_BANNED_WORD_LEN) bytes - lowercase
+}
+
+impl BannedWords {
+    // discriminator + words
+    pub const SPACE: usize = 8 + (4 + Self::MAX_BANNED_WORDS * (4 + Self::MAX_BANNED_WORD_LEN));
+
+    // Cap on the list; space for every entry is reserved up front
+    pub const MAX_BANNED_WORDS: usize = 20;
+
+    // Same as the board_id length cap, since words are matched against whole board IDs
+    pub const MAX_BANNED_WORD_LEN: usize = FeedbackBoard::MAX_BOARD_ID_LEN;
+}
+
+// Per-board pool of lamports the owner deposits and pays out to chosen contributors
+#[account]
+pub struct BountyVault {
+    pub board: Pubkey,          // 32 bytes
+    pub bounty_remaining: u64,  // 8 bytes - deposited lamports not yet claimed, excludes rent
+}
+
+// Per-creator list of board PDAs, for listing a creator's boards without a memcmp scan.
+// The account starts empty and create paths grow it by one entry (32 bytes) at a time,
+// with the creator paying the extra rent, up to MAX_INDEXED_BOARDS entries.
+#[account]
+pub struct CreatorBoardIndex {
+    pub creator: Pubkey,     // 32 bytes
+    pub boards: Vec<Pubkey>, // 4 + 32 * boards.len() bytes - in creation order
+}
+
+impl CreatorBoardIndex {
+    // Hard cap independent of max_boards_per_creator, keeping the account under 10 KiB
+    pub const MAX_INDEXED_BOARDS: usize = 300;
+
+    // discriminator + creator + boards (Borsh length prefix + entries)
+    pub fn space_for(board_count: usize) -> usize {
+        8 + 32 + (4 + 32 * board_count)
+    }
+}
+
+// One append_feedback submission, at ["entry", board, feedback_seq as little-endian u64].
+// Unlike the board's ipfs_cid, entries are never overwritten.
+#[account]
+pub struct FeedbackEntry {
+    pub board: Pubkey,    // 32 bytes
+    pub seq: u64,         // 8 bytes - the board's feedback_seq when the entry was appended
+    pub ipfs_cid: String, // 4 + up to FeedbackBoard::MAX_CID_LEN bytes
+    pub author: Pubkey,   // 32 bytes
+    pub timestamp: i64,   // 8 bytes - unix timestamp
+    pub bump: u8,         // 1 byte
+}
+
+impl FeedbackEntry {
+    // discriminator + board + seq + ipfs_cid (Borsh length prefix + max bytes) + author
+    // + timestamp + bump
+    pub const SPACE: usize = 8 + 32 + 8 + (4 + FeedbackBoard::MAX_CID_LEN) + 32 + 8 + 1;
+
+    // Cap on entries read by one get_entry_range call, keeping it well inside compute limits
+    pub const MAX_ENTRY_RANGE: u8 = 20;
+}
+
+// Per-wallet replay guard for submit_feedback: each submission must pass the current
+// submission_nonce, which then advances by one
+#[account]
+pub struct SubmitterRecord {
+    pub wallet: Pubkey,        // 32 bytes
+    pub submission_nonce: u64, // 8 bytes - nonce the wallet's next submit_feedback must pass
+    pub bump: u8,              // 1 byte
+}
+
+impl SubmitterRecord {
+    // discriminator + wallet + submission_nonce + bump
+    pub const SPACE: usize = 8 + 32 + 8 + 1;
+}
+
+#[account]
+pub struct CreatorStats {
     pub creator: Pubkey,  // 32 bytes
-    pub ipfs_cid: String, // 4 + up to 60 bytes (IPFS CIDs are typically ~46 chars)
-    pub board_id: String, // 4 + up to 28 bytes
+    pub board_count: u64, // 8 bytes - boards currently held against the per-creator cap
+    pub verified: bool,   // 1 byte - trusted-creator badge, only set by the platform admin
+}
+
+impl CreatorStats {
+    // discriminator + creator + board_count + verified
+    pub const SPACE: usize = 8 + 32 + 8 + 1;
 }
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::types::FeedbackBoard;
+use crate::errors::FeedbackBoardError::*;
+
+// Submission paths take the board as an UncheckedAccount so a board that was never
+// created fails with FeedbackBoardNotFound instead of Anchor's generic
+// AccountNotInitialized. The cost is that the checks `Account<FeedbackBoard>` and its
+// seeds constraint would make - owner, discriminator, PDA address and schema version -
+// are repeated here, and the caller must write the board back with store_feedback_board.
+pub fn load_feedback_board(board_info: &AccountInfo) -> Result<FeedbackBoard> {
+    // Validation: Check the board exists; a PDA that was never created holds no data
+    if board_info.data_is_empty() {
+        return Err(FeedbackBoardNotFound.into());
+    }
+
+    if board_info.owner != &crate::ID {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+
+    let feedback_board = FeedbackBoard::try_deserialize(&mut &board_info.try_borrow_data()?[..])?;
+
+    // Validation: Check the account is the board's PDA, using the stored bump
+    let expected_address = Pubkey::create_program_address(
+        &[
+            b"feedback_board",
+            feedback_board.creator.as_ref(),
+            feedback_board.board_id.as_bytes(),
+            &[feedback_board.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::ConstraintSeeds)?;
+
+    if board_info.key() != expected_address {
+        return Err(ErrorCode::ConstraintSeeds.into());
+    }
+
+    if feedback_board.schema_version != FeedbackBoard::CURRENT_SCHEMA_VERSION {
+        return Err(UnsupportedSchemaVersion.into());
+    }
+
+    Ok(feedback_board)
+}
+
+pub fn store_feedback_board(board_info: &AccountInfo, feedback_board: &FeedbackBoard) -> Result<()> {
+    feedback_board.try_serialize(&mut &mut board_info.try_borrow_mut_data()?[..])
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{FeedbackBoard, PlatformConfig};
+use crate::errors::FeedbackBoardError::*;
+use crate::utils::is_identifier_char;
+
+// Board IDs: normalized, within the platform's length limit and made of identifier
+// characters. board_id is a PDA seed, so every path deriving a board address checks it.
+pub fn validate_board_id(board_id: &str, platform_config: &PlatformConfig) -> Result<()> {
+    // Validation: Check if board_id is empty
+    if board_id.trim().is_empty() {
+        return Err(EmptyBoardId.into());
+    }
+
+    // Validation: Check if board_id is already in its canonical (trimmed, lowercase) form so
+    // that "MyBoard", "myboard" and "myboard " can't resolve to distinct PDAs
+    if board_id != board_id.trim().to_lowercase() {
+        return Err(BoardIdNotNormalized.into());
+    }
+
+    // Validation: Check if board_id is within the platform's configured length limit
+    if board_id.len() > usize::from(platform_config.max_board_id_len) {
+        return Err(BoardIdTooLong.into());
+    }
+
+    // Validation: Check if board_id is too short to squat
+    if board_id.len() < FeedbackBoard::MIN_BOARD_ID_LEN {
+        return Err(BoardIdTooShort.into());
+    }
+
+    // Validation: Check if board_id contains only valid characters (alphanumeric and hyphens)
+    if !board_id.chars().all(is_identifier_char) {
+        return Err(InvalidBoardIdChars.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::types::{PlatformConfig, StorageKind};
+use crate::errors::FeedbackBoardError::*;
+
+const BASE58BTC_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_LOWER_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz234567";
+const BASE36_LOWER_ALPHABET: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+// CIDv0 is always a base58btc sha2-256 multihash: "Qm" + 44 chars
+const CIDV0_LEN: usize = 46;
+
+// Arweave transaction IDs are a 32-byte hash in unpadded base64url
+const ARWEAVE_TX_ID_LEN: usize = 43;
+
+struct Multibase {
+    prefix: char,
+    alphabet: &'static str,
+    min_len: usize,
+}
+
+// Multibase encodings accepted for CIDv1. Minimum lengths correspond to a CID
+// carrying a 32-byte digest; the maximum comes from PlatformConfig.cid_max_len.
+const CIDV1_MULTIBASES: [Multibase; 3] = [
+    Multibase { prefix: 'b', alphabet: BASE32_LOWER_ALPHABET, min_len: 57 },
+    Multibase { prefix: 'k', alphabet: BASE36_LOWER_ALPHABET, min_len: 55 },
+    Multibase { prefix: 'z', alphabet: BASE58BTC_ALPHABET, min_len: 48 },
+];
+
+// Shared entry point for every instruction that accepts a CID, validated for the
+// board's storage network and the platform's CID length range
+pub fn validate_ipfs_cid(
+    cid: &str,
+    storage_kind: StorageKind,
+    platform_config: &PlatformConfig,
+) -> Result<()> {
+    // Validation: Check if the CID is empty or whitespace-only
+    if cid.trim().is_empty() {
+        return Err(EmptyIpfsCid.into());
+    }
+
+    match storage_kind {
+        StorageKind::Ipfs => validate_cid(
+            cid,
+            usize::from(platform_config.cid_min_len),
+            usize::from(platform_config.cid_max_len),
+        ),
+        StorageKind::Arweave => validate_arweave_tx_id(cid),
+    }
+}
+
+pub fn validate_arweave_tx_id(tx_id: &str) -> Result<()> {
+    let valid = tx_id.len() == ARWEAVE_TX_ID_LEN
+        && tx_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if !valid {
+        return Err(InvalidArweaveTxId.into());
+    }
+
+    Ok(())
+}
+
+pub fn validate_cid(cid: &str, cid_min_len: usize, cid_max_len: usize) -> Result<()> {
+    // Validation: Check the platform-wide length range
+    if cid.len() < cid_min_len || cid.len() > cid_max_len {
+        return Err(InvalidIpfsCidLength.into());
+    }
+
+    // Resolve the encoding: CIDv0 ("Qm...") or a CIDv1 multibase prefix
+    let (body, alphabet, min_len, max_len) = if cid.starts_with("Qm") {
+        (cid, BASE58BTC_ALPHABET, CIDV0_LEN, CIDV0_LEN)
+    } else {
+        let prefix = cid.chars().next().ok_or(EmptyIpfsCid)?;
+        let multibase = CIDV1_MULTIBASES
+            .iter()
+            .find(|multibase| multibase.prefix == prefix)
+            .ok_or(InvalidIpfsCid)?;
+        (
+            &cid[prefix.len_utf8()..],
+            multibase.alphabet,
+            multibase.min_len,
+            cid_max_len,
+        )
+    };
+
+    // Validation: Check the length range for this encoding
+    if cid.len() < min_len || cid.len() > max_len {
+        return Err(InvalidIpfsCidLength.into());
+    }
+
+    // Validation: Check every character belongs to the encoding's alphabet
+    if !body.chars().all(|c| alphabet.contains(c)) {
+        return Err(InvalidIpfsCid.into());
+    }
+
+    Ok(())
+}
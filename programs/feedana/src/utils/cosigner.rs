@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::types::FeedbackBoard;
+use crate::errors::FeedbackBoardError::*;
+
+// Two-party control: boards with a cosigner need it to sign sensitive instructions
+// alongside the owner. Boards without one pass unchanged.
+pub fn check_cosigner(feedback_board: &FeedbackBoard, cosigner: Option<&Signer>) -> Result<()> {
+    let Some(required_cosigner) = feedback_board.cosigner else {
+        return Ok(());
+    };
+
+    let cosigned = cosigner.is_some_and(|cosigner| cosigner.key() == required_cosigner);
+    if !cosigned {
+        return Err(CosignerRequired.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::types::FeedbackBoard;
+use crate::errors::FeedbackBoardError::*;
+
+// Encryption hint: encrypted boards must name their scheme, plaintext boards must not
+pub fn validate_encryption(encrypted: bool, encryption_scheme: Option<&str>) -> Result<()> {
+    let valid = match encryption_scheme {
+        Some(scheme) => {
+            encrypted
+                && !scheme.trim().is_empty()
+                && scheme.len() <= FeedbackBoard::MAX_ENCRYPTION_SCHEME_LEN
+        }
+        None => !encrypted,
+    };
+
+    if !valid {
+        return Err(InvalidEncryptionScheme.into());
+    }
+
+    Ok(())
+}
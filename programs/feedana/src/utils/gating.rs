@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::accounts::Metadata;
+
+use crate::types::FeedbackBoard;
+use crate::errors::FeedbackBoardError::*;
+
+// Token gating: the giver must pass their token account for the gate mint holding
+// at least max(gate_min_balance, 1) tokens
+pub fn check_token_gate(
+    feedback_board: &FeedbackBoard,
+    feedback_giver: Pubkey,
+    gate_token_account: Option<&TokenAccount>,
+) -> Result<()> {
+    let Some(gate_mint) = feedback_board.gate_mint else {
+        return Ok(());
+    };
+
+    let required_balance = feedback_board.gate_min_balance.max(1);
+    let holds_enough = gate_token_account.is_some_and(|token_account| {
+        token_account.mint == gate_mint
+            && token_account.owner == feedback_giver
+            && token_account.amount >= required_balance
+    });
+    if !holds_enough {
+        return Err(InsufficientGateBalance.into());
+    }
+
+    Ok(())
+}
+
+// NFT collection gating: the giver must hold an NFT whose Metaplex metadata lists the
+// board's collection as verified
+pub fn check_collection_gate(
+    feedback_board: &FeedbackBoard,
+    feedback_giver: Pubkey,
+    nft_token_account: Option<&TokenAccount>,
+    nft_metadata: Option<&AccountInfo>,
+) -> Result<()> {
+    let Some(collection_mint) = feedback_board.collection_mint else {
+        return Ok(());
+    };
+
+    let (Some(token_account), Some(metadata_info)) = (nft_token_account, nft_metadata) else {
+        return Err(NotCollectionHolder.into());
+    };
+
+    // Validation: Check the giver holds the NFT
+    if token_account.owner != feedback_giver || token_account.amount == 0 {
+        return Err(NotCollectionHolder.into());
+    }
+
+    // Validation: Check the metadata account is the canonical Metaplex PDA for the NFT mint
+    let (metadata_pda, _) = Metadata::find_pda(&token_account.mint);
+    if metadata_info.key() != metadata_pda || *metadata_info.owner != mpl_token_metadata::ID {
+        return Err(NotCollectionHolder.into());
+    }
+
+    // Validation: Check the NFT belongs to the verified collection
+    let metadata = Metadata::safe_deserialize(&metadata_info.try_borrow_data()?)
+        .map_err(|_| NotCollectionHolder)?;
+    let in_collection = metadata
+        .collection
+        .is_some_and(|collection| collection.verified && collection.key == collection_mint);
+    if !in_collection {
+        return Err(NotCollectionHolder.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+pub mod board;
+pub mod board_id;
+pub mod cid;
+pub mod cosigner;
+pub mod encryption;
+pub mod gating;
+pub mod tags;
+pub mod title;
+pub mod wallet;
+
+pub use board::*;
+pub use board_id::*;
+pub use cid::*;
+pub use cosigner::*;
+pub use encryption::*;
+pub use gating::*;
+pub use tags::*;
+pub use title::*;
+pub use wallet::*;
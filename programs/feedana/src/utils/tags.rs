@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::types::FeedbackBoard;
+use crate::errors::FeedbackBoardError::*;
+
+// Characters allowed in board IDs and tags: alphanumeric, hyphens and underscores
+pub fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+// Discovery tags: at most MAX_TAGS, each 1..=MAX_TAG_LEN bytes of identifier characters
+pub fn validate_tags(tags: &[String]) -> Result<()> {
+    if tags.len() > FeedbackBoard::MAX_TAGS {
+        return Err(TooManyTags.into());
+    }
+
+    for tag in tags {
+        let valid = !tag.is_empty()
+            && tag.len() <= FeedbackBoard::MAX_TAG_LEN
+            && tag.chars().all(is_identifier_char);
+
+        if !valid {
+            return Err(InvalidTag.into());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::types::FeedbackBoard;
+use crate::errors::FeedbackBoardError::*;
+
+// Board titles: any text that isn't blank, up to MAX_TITLE_LEN bytes
+pub fn validate_board_title(title: &str) -> Result<()> {
+    if title.trim().is_empty() {
+        return Err(EmptyTitle.into());
+    }
+
+    if title.len() > FeedbackBoard::MAX_TITLE_LEN {
+        return Err(TitleTooLong.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::FeedbackBoardError::*;
+
+// Wallets passed as instruction arguments rather than signers: the default key, which is
+// also the system program's ID, is never a real wallet, so catch clients passing a placeholder
+pub fn validate_wallet(wallet: &Pubkey) -> Result<()> {
+    if *wallet == Pubkey::default() {
+        return Err(InvalidSigner.into());
+    }
+
+    Ok(())
+}